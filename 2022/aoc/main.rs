@@ -0,0 +1,633 @@
+use std::cmp::Ordering;
+use std::io::IsTerminal;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+use advent_of_code::bench::{self, BenchOptions, DayBench};
+use advent_of_code::day07::Day07;
+use advent_of_code::day10::cpu::Cpu;
+use advent_of_code::day10::{self, ocr, Day10};
+use advent_of_code::day11::worry::WorryType;
+use advent_of_code::day11::{Day11, Relief};
+use advent_of_code::day12::{self, Day12, RouteStyle};
+use advent_of_code::day13::Day13;
+use advent_of_code::day14::{CaveOptions, Day14, Replay, Source};
+use advent_of_code::day15::{export, Day15};
+use advent_of_code::error::ParseError;
+use advent_of_code::input::InputSource;
+use advent_of_code::solution::{Answer, Solution};
+use advent_of_code::{registry, Day, Part};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions")]
+struct Cli
+{
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command
+{
+    /// Run a single day, a range of days or every solved day of a year
+    Run
+    {
+        year: u16,
+        /// Day number or inclusive range like `10-15`; every solved day when omitted
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Run only this part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Time parsing and both parts of the selected days
+    Bench
+    {
+        year: u16,
+        /// Day number or inclusive range like `10-15`; every solved day when omitted
+        #[arg(value_parser = parse_days)]
+        days: Option<RangeInclusive<u8>>,
+        /// Unmeasured runs of every step before the measured ones
+        #[arg(long, default_value_t = BenchOptions::default().warmup)]
+        warmup: usize,
+        /// Measured runs of every step
+        #[arg(long, default_value_t = BenchOptions::default().iterations, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        iterations: usize,
+        /// Print the timings as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Print the filesystem reconstructed from the 2022 day 7 transcript like tree(1)
+    Tree
+    {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show the CRT screen drawn by the 2022 day 10 program and the letters read from it
+    Crt
+    {
+        /// Draw lit pixels as full blocks instead of `#` and `.`
+        #[arg(long)]
+        pretty: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Trace the 2022 day 10 program cycle by cycle
+    Cpu
+    {
+        /// Only show the state during this cycle; can be repeated
+        #[arg(long = "break", value_name = "CYCLE")]
+        breakpoints: Vec<usize>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Compute the 2022 day 11 monkey business, reporting the inspection that overflows
+    Monkeys
+    {
+        /// Number type of the worry levels: i64, i128 or bigint
+        #[arg(long, default_value_t = WorryType::I64)]
+        worry: WorryType,
+        /// Rounds of part 2
+        #[arg(long, default_value_t = 10000)]
+        rounds: u64,
+        /// Extrapolate part 2 from the cycles of every item instead of playing all rounds
+        #[arg(long)]
+        cycles: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Draw the shortest 2022 day 12 route onto the height map
+    Route
+    {
+        /// Route of this part: 1 starts at S, 2 at the best of the lowest cells
+        #[arg(long, default_value_t = Part::One)]
+        part: Part,
+        /// Print the puzzle's plain notation even when the output is a terminal
+        #[arg(long)]
+        plain: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Tell which 2022 day 13 packet pairs are in the right order
+    Packets
+    {
+        /// Walk through every comparison step by step like the puzzle description
+        #[arg(long)]
+        explain: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Replay the 2022 day 14 sand falling into the cave frame by frame
+    Replay
+    {
+        #[command(flatten)]
+        cave: CaveArgs,
+        /// Draw the way the latest grain fell
+        #[arg(long)]
+        paths: bool,
+        /// Frames per second
+        #[arg(long, default_value_t = 30.0)]
+        fps: f64,
+        /// Only show every Nth grain
+        #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        every: usize,
+        /// Write the frames to DIR/frame_NNNNN.txt, numbered by grains at rest, instead of playing them
+        #[arg(long, value_name = "DIR")]
+        dump: Option<PathBuf>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Map what the 2022 day 15 sensors cover in the search box and where the distress beacon is
+    Coverage
+    {
+        /// Width of the map, larger search boxes are shrunk to fit
+        #[arg(long, default_value_t = 64, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        columns: usize,
+        /// Also draw the sensors, beacons, their diamonds and the gap as SVG into this file
+        #[arg(long, value_name = "PATH")]
+        svg: Option<PathBuf>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Args)]
+struct InputArgs
+{
+    /// Read the input from this file (`-` for stdin) instead of `dayNN/input.txt`
+    /// under $AOC_INPUT_DIR or the crate directory
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// Use the example from the puzzle description (`dayNN/test_input.txt`);
+    /// together with --input marks that file as an example
+    #[arg(long)]
+    example: bool,
+}
+
+#[derive(Args)]
+struct CaveArgs
+{
+    /// Simulate part 2, with the floor two rows under the lowest rock
+    #[arg(long)]
+    floor: bool,
+    /// Put the floor this many rows under the lowest rock
+    #[arg(long, value_name = "ROWS", conflicts_with = "floor")]
+    floor_offset: Option<i64>,
+    /// Pour from here instead of 500,0, `x,y` for sand or `x,y:water`; can be repeated
+    #[arg(long = "source", value_name = "SOURCE")]
+    sources: Vec<Source>,
+    /// Solid walls at these two columns, like `480,520`
+    #[arg(long, value_name = "LEFT,RIGHT", value_parser = parse_walls)]
+    walls: Option<(i64, i64)>,
+}
+
+impl From<CaveArgs> for CaveOptions
+{
+    fn from(args: CaveArgs) -> Self
+    {
+        CaveOptions {
+            sources: if args.sources.is_empty() { vec![Source::PUZZLE] } else { args.sources },
+            floor_offset: args.floor_offset.or(args.floor.then_some(2)),
+            walls: args.walls,
+        }
+    }
+}
+
+fn parse_walls(input: &str) -> Result<(i64, i64), String>
+{
+    let walls = input.split_once(',').and_then(|(left, right)| Some((left.trim().parse().ok()?, right.trim().parse().ok()?)));
+    match walls
+    {
+        Some((left, right)) if left < right => Ok((left, right)),
+        _ => Err(format!("expected two increasing columns like `480,520`, got `{}`", input)),
+    }
+}
+
+impl From<InputArgs> for InputSource
+{
+    fn from(args: InputArgs) -> Self
+    {
+        InputSource { path: args.input, example: args.example }
+    }
+}
+
+fn parse_days(input: &str) -> Result<RangeInclusive<u8>, String>
+{
+    let parse_day = |day: &str| day.trim().parse::<u8>().map_err(|_| format!("`{}` is not a day number", day));
+    match input.split_once('-')
+    {
+        Some((first, last)) => Ok(parse_day(first)?..=parse_day(last)?),
+        None => parse_day(input).map(|day| day..=day),
+    }
+}
+
+fn print_answer(part: Part, answer: &Answer)
+{
+    match answer
+    {
+        Answer::Grid(picture) => println!("Part {}:\n{}", part, picture),
+        _ => println!("Part {}: {}", part, answer),
+    }
+}
+
+/// Days of `year` matching the selection, `None` after reporting why there are none.
+fn select_days<'a>(registered_days: &'a [Day], year: u16, days: &Option<RangeInclusive<u8>>, input: &InputSource) -> Option<Vec<&'a Day>>
+{
+    let selected_days: Vec<&Day> = registered_days.iter()
+        .filter(|day| days.as_ref().is_none_or(|range| range.contains(&day.number)))
+        .collect();
+    if selected_days.is_empty()
+    {
+        eprintln!("aoc: no solved days of {} match the selection", year);
+        return None;
+    }
+    if input.path.is_some() && selected_days.len() > 1
+    {
+        eprintln!("aoc: --input can only be used with a single day");
+        return None;
+    }
+    if selected_days.len() == 1
+    {
+        return Some(selected_days);
+    }
+    // Not every day has a puzzle input in the repository; a missing one only fails a run of that single day.
+    Some(selected_days.into_iter().filter(|day|
+    {
+        let path = input.resolve(day.number).filter(|path| !path.exists());
+        if let Some(path) = &path
+        {
+            eprintln!("aoc: skipping day {:02}, there is no input at {}", day.number, path.display());
+        }
+        path.is_none()
+    }).collect())
+}
+
+fn run(year: u16, days: Option<RangeInclusive<u8>>, part: Option<Part>, input: InputSource) -> ExitCode
+{
+    let Some(registered_days) = registry(year) else
+    {
+        eprintln!("aoc: there are no solutions for {}", year);
+        return ExitCode::FAILURE;
+    };
+    let Some(selected_days) = select_days(&registered_days, year, &days, &input) else
+    {
+        return ExitCode::FAILURE;
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in selected_days
+    {
+        println!("--- {} Day {:02} ---", year, day.number);
+        let contents = match input.read(day.number)
+        {
+            Ok(contents) => contents,
+            Err(error) =>
+            {
+                eprintln!("aoc: cannot read input: {}", error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let solution = day.solution(input.example);
+        let parsed_input = match solution.parse_any(&contents)
+        {
+            Ok(parsed_input) => parsed_input,
+            Err(error) =>
+            {
+                eprintln!("aoc: cannot parse input of {}", error.with_day(day.number));
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        for selected_part in [Part::One, Part::Two]
+        {
+            if selected_part.is_selected(part)
+            {
+                match solution.solve(parsed_input.as_ref(), selected_part)
+                {
+                    Answer::Error(message) =>
+                    {
+                        eprintln!("aoc: cannot solve part {} of day {:02}: {}", selected_part, day.number, message);
+                        exit_code = ExitCode::FAILURE;
+                    }
+                    answer => print_answer(selected_part, &answer),
+                }
+            }
+        }
+    }
+    exit_code
+}
+
+fn print_bench_table(year: u16, results: &[DayBench])
+{
+    println!("--- {} ---", year);
+    println!("{:<6} {:<8} {:>12} {:>12} {:>12}", "day", "step", "min", "median", "mean");
+    for result in results
+    {
+        for (step, timings) in result.steps()
+        {
+            println!("day {:02} {:<8} {:>12.2?} {:>12.2?} {:>12.2?}", result.day, step, timings.min, timings.median, timings.mean);
+        }
+        for speedup in &result.speedups
+        {
+            println!("day {:02} {}: median {:.2?} on 1 thread, {:.2?} on {} thread(s), {:.2}x faster",
+                result.day, speedup.step, speedup.sequential.median, speedup.parallel.median, speedup.threads, speedup.ratio());
+        }
+    }
+}
+
+fn bench(year: u16, days: Option<RangeInclusive<u8>>, options: BenchOptions, json: bool, input: InputSource) -> ExitCode
+{
+    let Some(registered_days) = registry(year) else
+    {
+        eprintln!("aoc: there are no solutions for {}", year);
+        return ExitCode::FAILURE;
+    };
+    let Some(selected_days) = select_days(&registered_days, year, &days, &input) else
+    {
+        return ExitCode::FAILURE;
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut results = Vec::new();
+    for day in selected_days
+    {
+        let measured = input.read(day.number)
+            .map_err(|error| format!("cannot read input: {}", error))
+            .and_then(|contents| bench::bench_day(day.number, day.solution(input.example), &contents, options)
+                .map_err(|error| format!("cannot parse input of {}", error.with_day(day.number))));
+        match measured
+        {
+            Ok(result) => results.push(result),
+            Err(message) =>
+            {
+                eprintln!("aoc: {}", message);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if json
+    {
+        println!("{:#}", bench::to_json(year, options, &results));
+    }
+    else
+    {
+        print_bench_table(year, &results);
+    }
+    exit_code
+}
+
+/// Reads and parses the input of a single 2022 day for one of the debugging commands.
+fn parse_day_input<S: Solution>(day: u8, solution: &S, input: &InputSource) -> Result<S::Input, ExitCode>
+{
+    let contents = input.read(day).map_err(|error|
+    {
+        eprintln!("aoc: cannot read input: {}", error);
+        ExitCode::FAILURE
+    })?;
+    solution.parse(&contents).map_err(|error: ParseError|
+    {
+        eprintln!("aoc: cannot parse input of {}", error.with_day(day));
+        ExitCode::FAILURE
+    })
+}
+
+fn tree(input: InputSource) -> ExitCode
+{
+    match parse_day_input(7, &Day07, &input)
+    {
+        Ok(file_system) =>
+        {
+            println!("{}", file_system.render_tree());
+            ExitCode::SUCCESS
+        }
+        Err(exit_code) => exit_code,
+    }
+}
+
+fn crt(pretty: bool, input: InputSource) -> ExitCode
+{
+    let program = match parse_day_input(10, &Day10::default(), &input)
+    {
+        Ok(program) => program,
+        Err(exit_code) => return exit_code,
+    };
+    let picture = day10::draw_picture(&program, day10::WIDTH, day10::HEIGHT);
+    if pretty
+    {
+        println!("{}", ocr::pretty(&picture));
+    }
+    else
+    {
+        println!("{}", picture.render(|&lit| if lit { '#' } else { '.' }));
+    }
+    match ocr::decode(&picture)
+    {
+        Ok(letters) =>
+        {
+            println!("{}", letters);
+            ExitCode::SUCCESS
+        }
+        Err(unknown_glyph) =>
+        {
+            eprintln!("aoc: {}", unknown_glyph);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn cpu(breakpoints: Vec<usize>, input: InputSource) -> ExitCode
+{
+    let program = match parse_day_input(10, &Day10::default(), &input)
+    {
+        Ok(program) => program,
+        Err(exit_code) => return exit_code,
+    };
+    if breakpoints.is_empty()
+    {
+        println!("{}", Cpu::trace(&program));
+    }
+    else
+    {
+        let mut cpu = Cpu::new(&program).with_breakpoints(breakpoints);
+        while let Some(state) = cpu.resume()
+        {
+            println!("{}", state);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn monkeys(worry: WorryType, rounds: u64, cycles: bool, input: InputSource) -> ExitCode
+{
+    let solution = Day11::default().with_worry(worry);
+    let monkeys = match parse_day_input(11, &solution, &input)
+    {
+        Ok(monkeys) => monkeys,
+        Err(exit_code) => return exit_code,
+    };
+    let part_1 = solution.try_monkey_business(&monkeys, 20, Relief::DivideByThree).map(|business| business as u128);
+    let part_2 = if cycles
+    {
+        solution.try_monkey_business_by_cycles(&monkeys, rounds)
+    }
+    else
+    {
+        solution.try_monkey_business(&monkeys, rounds as usize, Relief::ModuloDivisors).map(|business| business as u128)
+    };
+    let mut exit_code = ExitCode::SUCCESS;
+    for (part, result) in [(Part::One, part_1), (Part::Two, part_2)]
+    {
+        match result
+        {
+            Ok(business) => println!("Part {}: {}", part, business),
+            Err(error) =>
+            {
+                eprintln!("aoc: part {}: {}", part, error);
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+    exit_code
+}
+
+fn route(part: Part, plain: bool, input: InputSource) -> ExitCode
+{
+    let height_map = match parse_day_input(12, &Day12, &input)
+    {
+        Ok(height_map) => height_map,
+        Err(exit_code) => return exit_code,
+    };
+    let route = match part
+    {
+        Part::One => day12::route_from_start(&height_map),
+        Part::Two => day12::route_from_lowest(&height_map),
+    };
+    let Some(route) = route else
+    {
+        eprintln!("aoc: E can't be reached");
+        return ExitCode::FAILURE;
+    };
+    let style = if plain || !std::io::stdout().is_terminal() { RouteStyle::Plain } else { RouteStyle::Ansi };
+    println!("{}", day12::render_route(&height_map, &route, style));
+    println!("{} steps", route.len() - 1);
+    ExitCode::SUCCESS
+}
+
+fn packets(explain: bool, input: InputSource) -> ExitCode
+{
+    let pairs = match parse_day_input(13, &Day13, &input)
+    {
+        Ok(pairs) => pairs,
+        Err(exit_code) => return exit_code,
+    };
+    for (index, pair) in pairs.iter().enumerate()
+    {
+        let explanation = pair[0].compare_explained(&pair[1]);
+        println!("== Pair {} ==", index + 1);
+        if explain
+        {
+            println!("{}", explanation);
+        }
+        else
+        {
+            println!("{}\n{}", pair[0], pair[1]);
+            println!("{}", match explanation.ordering
+            {
+                Ordering::Less => "in the right order",
+                Ordering::Equal => "equal",
+                Ordering::Greater => "not in the right order",
+            });
+        }
+        println!();
+    }
+    ExitCode::SUCCESS
+}
+
+fn replay(cave: CaveOptions, paths: bool, fps: f64, every: usize, dump: Option<PathBuf>, input: InputSource) -> ExitCode
+{
+    let lines = match parse_day_input(14, &Day14::default(), &input)
+    {
+        Ok(lines) => lines,
+        Err(exit_code) => return exit_code,
+    };
+    let replay = Replay::record_cave(&lines, &cave, paths);
+    if let Some(directory) = dump
+    {
+        if let Err(error) = std::fs::create_dir_all(&directory)
+        {
+            eprintln!("aoc: cannot create {}: {}", directory.display(), error);
+            return ExitCode::FAILURE;
+        }
+        for (grains, frame) in replay.frames(every)
+        {
+            let path = directory.join(format!("frame_{:05}.txt", grains));
+            if let Err(error) = std::fs::write(&path, frame + "\n")
+            {
+                eprintln!("aoc: cannot write {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+    let delay = std::time::Duration::from_secs_f64(1.0 / fps.max(0.001));
+    for (grains, frame) in replay.frames(every)
+    {
+        // Moves the cursor home and clears the screen before every frame.
+        println!("\x1b[H\x1b[2J{}\n{} grains at rest", frame, grains);
+        std::thread::sleep(delay);
+    }
+    ExitCode::SUCCESS
+}
+
+fn coverage(columns: usize, svg: Option<PathBuf>, input: InputSource) -> ExitCode
+{
+    let day = if input.example { Day15::example() } else { Day15::default() };
+    let sensors = match parse_day_input(15, &day, &input)
+    {
+        Ok(sensors) => sensors,
+        Err(exit_code) => return exit_code,
+    };
+    let gap = day.distress_beacon(&sensors);
+    println!("{}", export::ascii(&sensors, &day.search_box, gap, columns));
+    match gap
+    {
+        Some((x, y)) => println!("distress beacon at x={}, y={}", x, y),
+        None => println!("every position of the search box is covered"),
+    }
+    if let Some(path) = svg
+    {
+        if let Err(error) = std::fs::write(&path, export::svg(&sensors, &day.search_box, gap))
+        {
+            eprintln!("aoc: cannot write {}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode
+{
+    match Cli::parse().command
+    {
+        Command::Run { year, days, part, input } => run(year, days, part, input.into()),
+        Command::Bench { year, days, warmup, iterations, json, input } =>
+            bench(year, days, BenchOptions { warmup, iterations }, json, input.into()),
+        Command::Tree { input } => tree(input.into()),
+        Command::Crt { pretty, input } => crt(pretty, input.into()),
+        Command::Cpu { breakpoints, input } => cpu(breakpoints, input.into()),
+        Command::Monkeys { worry, rounds, cycles, input } => monkeys(worry, rounds, cycles, input.into()),
+        Command::Route { part, plain, input } => route(part, plain, input.into()),
+        Command::Packets { explain, input } => packets(explain, input.into()),
+        Command::Replay { cave, paths, fps, every, dump, input } => replay(cave.into(), paths, fps, every, dump, input.into()),
+        Command::Coverage { columns, svg, input } => coverage(columns, svg, input.into()),
+    }
+}
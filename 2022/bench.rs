@@ -0,0 +1,172 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::error::ParseError;
+use crate::solution::Solver;
+use crate::Part;
+
+/// How many times every step runs before and while it is measured.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions
+{
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions
+{
+    fn default() -> Self
+    {
+        BenchOptions { warmup: 1, iterations: 10 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings
+{
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Timings
+{
+    /// Summarizes the samples, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self
+    {
+        assert!(!samples.is_empty(), "nothing was measured");
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) { (samples[middle - 1] + samples[middle]) / 2 } else { samples[middle] };
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Timings { min: samples[0], median, mean }
+    }
+
+    fn to_json(self) -> Value
+    {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+        })
+    }
+}
+
+/// Timings of the steps of a single day.
+#[derive(Debug, Clone)]
+pub struct DayBench
+{
+    pub day: u8,
+    pub parse: Timings,
+    pub part_1: Timings,
+    pub part_2: Timings,
+    /// Steps that were also measured on several threads.
+    pub speedups: Vec<Speedup>,
+}
+
+impl DayBench
+{
+    pub fn steps(&self) -> [(&'static str, Timings); 3]
+    {
+        [("parse", self.parse), ("part_1", self.part_1), ("part_2", self.part_2)]
+    }
+}
+
+/// The same step measured in a rayon pool of one thread and in the global pool.
+#[derive(Debug, Clone)]
+pub struct Speedup
+{
+    pub step: &'static str,
+    pub threads: usize,
+    pub sequential: Timings,
+    pub parallel: Timings,
+}
+
+impl Speedup
+{
+    /// How many times faster the median parallel run is.
+    pub fn ratio(&self) -> f64
+    {
+        self.sequential.median.as_secs_f64() / self.parallel.median.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    fn to_json(&self) -> Value
+    {
+        json!({
+            "step": self.step,
+            "threads": self.threads,
+            "sequential": self.sequential.to_json(),
+            "parallel": self.parallel.to_json(),
+            "speedup": self.ratio(),
+        })
+    }
+}
+
+/// Measures `step`, which runs its work on the current rayon pool, on one thread and then on the global pool.
+pub fn measure_speedup<R: Send>(options: BenchOptions, name: &'static str, step: impl Fn() -> R + Sync) -> Speedup
+{
+    let one_thread = rayon::ThreadPoolBuilder::new().num_threads(1).build().expect("cannot start a thread pool");
+    Speedup
+    {
+        step: name,
+        threads: rayon::current_num_threads(),
+        sequential: one_thread.install(|| measure(options, &step)),
+        parallel: measure(options, &step),
+    }
+}
+
+/// Runs `step` `options.warmup` times unmeasured, then `options.iterations` times measured.
+pub fn measure<R>(options: BenchOptions, mut step: impl FnMut() -> R) -> Timings
+{
+    for _ in 0..options.warmup
+    {
+        black_box(step());
+    }
+    let samples = (0..options.iterations.max(1)).map(|_|
+    {
+        let start = Instant::now();
+        black_box(step());
+        start.elapsed()
+    }).collect();
+    Timings::from_samples(samples)
+}
+
+/// Measures parsing and both parts of a day on `contents`; the parts reuse one parsed input.
+pub fn bench_day(day: u8, solver: &dyn Solver, contents: &str, options: BenchOptions) -> Result<DayBench, ParseError>
+{
+    let input = solver.parse_any(contents)?;
+    Ok(DayBench
+    {
+        day,
+        parse: measure(options, || solver.parse_any(contents)),
+        part_1: measure(options, || solver.solve(input.as_ref(), Part::One)),
+        part_2: measure(options, || solver.solve(input.as_ref(), Part::Two)),
+        speedups: solver.speedups_any(input.as_ref(), options),
+    })
+}
+
+/// Machine-readable report, stable enough to be diffed between commits.
+pub fn to_json(year: u16, options: BenchOptions, results: &[DayBench]) -> Value
+{
+    let days: Vec<Value> = results.iter().map(|result|
+    {
+        let mut day = json!({ "day": result.day });
+        for (step, timings) in result.steps()
+        {
+            day[step] = timings.to_json();
+        }
+        if !result.speedups.is_empty()
+        {
+            day["speedups"] = result.speedups.iter().map(Speedup::to_json).collect();
+        }
+        day
+    }).collect();
+    json!({
+        "year": year,
+        "warmup": options.warmup,
+        "iterations": options.iterations,
+        "days": days,
+    })
+}
@@ -0,0 +1,392 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::{Bound, Index, IndexMut};
+
+use crate::error::{numbered_lines, ParseError};
+
+/// `(row, column)` of a cell in a [`Grid`].
+pub type Position = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// Dense rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T>
+{
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T>
+{
+    pub fn new(rows: usize, columns: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![value; rows * columns], rows, columns }
+    }
+
+    /// Takes the cells row by row, `cells.len()` has to be `rows * columns`.
+    pub fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self
+    {
+        assert_eq!(cells.len(), rows * columns, "cells don't fill a {}x{} grid", rows, columns);
+        Grid { cells, rows, columns }
+    }
+
+    /// Reads one cell per character, empty lines are skipped. `expected` names
+    /// the accepted characters in the error for the ones `parse_cell` rejects.
+    pub fn parse(contents: &str, expected: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError>
+    {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut columns = 0;
+        for (number, line) in numbered_lines(contents)
+        {
+            let row_start = cells.len();
+            for (column, ch) in line.chars().enumerate()
+            {
+                let cell = parse_cell(ch).ok_or_else(|| ParseError::new(number, column + 1, line, format!("expected {}", expected)))?;
+                cells.push(cell);
+            }
+            let row_length = cells.len() - row_start;
+            if rows == 0
+            {
+                columns = row_length;
+            }
+            else if row_length != columns
+            {
+                return Err(ParseError::new(number, 1, line, format!("expected a row of {} cells, found {}", columns, row_length)));
+            }
+            rows += 1;
+        }
+        Ok(Grid { cells, rows, columns })
+    }
+
+    pub fn rows(&self) -> usize
+    {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize
+    {
+        self.columns
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T>
+    {
+        (row < self.rows && column < self.columns).then(|| &self.cells[row * self.columns + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T>
+    {
+        (row < self.rows && column < self.columns).then(|| &mut self.cells[row * self.columns + column])
+    }
+
+    pub fn row(&self, row: usize) -> &[T]
+    {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator
+    {
+        assert!(column < self.columns, "column {} is out of a grid with {} columns", column, self.columns);
+        self.cells.iter().skip(column).step_by(self.columns)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position>
+    {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |row| (0..columns).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)>
+    {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position>
+    {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// Up, down, left and right neighbours that are inside the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_
+    {
+        self.shifted(position, &NEIGHBOURS_4)
+    }
+
+    /// Neighbours sharing a side or a corner that are inside the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_
+    {
+        self.shifted(position, &NEIGHBOURS_8)
+    }
+
+    fn shifted<'a>(&'a self, (row, column): Position, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = Position> + 'a
+    {
+        offsets.iter().filter_map(move |&(row_offset, column_offset)|
+        {
+            let neighbour = (row.checked_add_signed(row_offset)?, column.checked_add_signed(column_offset)?);
+            (neighbour.0 < self.rows && neighbour.1 < self.columns).then_some(neighbour)
+        })
+    }
+
+    /// The grid as seen after turning it, without copying the cells.
+    pub fn rotated(&self, rotation: Rotation) -> RotatedView<'_, T>
+    {
+        RotatedView { grid: self, rotation }
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U>
+    {
+        Grid { cells: self.cells.iter().map(f).collect(), rows: self.rows, columns: self.columns }
+    }
+
+    /// One character per cell, rows separated by `\n`.
+    pub fn render(&self, symbol: impl Fn(&T) -> char) -> String
+    {
+        (0..self.rows).map(|row| self.row(row).iter().map(&symbol).collect::<String>()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl<T> Index<Position> for Grid<T>
+{
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T
+    {
+        self.get(position).unwrap_or_else(|| panic!("{:?} is out of a {}x{} grid", position, self.rows, self.columns))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T>
+{
+    fn index_mut(&mut self, position: Position) -> &mut T
+    {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} is out of a {}x{} grid", position, rows, columns))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        for row in 0..self.rows
+        {
+            if row > 0
+            {
+                writeln!(f)?;
+            }
+            for cell in self.row(row)
+            {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Clockwise turn of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation
+{
+    None,
+    Clockwise,
+    Half,
+    CounterClockwise,
+}
+
+impl Rotation
+{
+    pub const ALL: [Rotation; 4] = [Rotation::None, Rotation::Clockwise, Rotation::Half, Rotation::CounterClockwise];
+}
+
+#[derive(Clone, Copy)]
+pub struct RotatedView<'a, T>
+{
+    grid: &'a Grid<T>,
+    rotation: Rotation,
+}
+
+impl<T> RotatedView<'_, T>
+{
+    pub fn rows(&self) -> usize
+    {
+        match self.rotation
+        {
+            Rotation::None | Rotation::Half => self.grid.rows,
+            Rotation::Clockwise | Rotation::CounterClockwise => self.grid.columns,
+        }
+    }
+
+    pub fn columns(&self) -> usize
+    {
+        match self.rotation
+        {
+            Rotation::None | Rotation::Half => self.grid.columns,
+            Rotation::Clockwise | Rotation::CounterClockwise => self.grid.rows,
+        }
+    }
+
+    /// Position in the underlying grid of the cell seen at `(row, column)`.
+    pub fn original(&self, (row, column): Position) -> Position
+    {
+        let (rows, columns) = (self.grid.rows, self.grid.columns);
+        match self.rotation
+        {
+            Rotation::None => (row, column),
+            Rotation::Clockwise => (rows - column - 1, row),
+            Rotation::Half => (rows - row - 1, columns - column - 1),
+            Rotation::CounterClockwise => (column, columns - row - 1),
+        }
+    }
+}
+
+impl<T> Index<Position> for RotatedView<'_, T>
+{
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T
+    {
+        &self.grid[self.original(position)]
+    }
+}
+
+/// `(x, y)` of a cell in a [`SparseGrid`], `y` grows downwards.
+pub type Point = (i64, i64);
+
+/// Unbounded grid that only stores the occupied cells, column by column so
+/// that the first occupied cell below a point is cheap to find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T>
+{
+    columns: HashMap<i64, BTreeMap<i64, T>>,
+}
+
+impl<T> Default for SparseGrid<T>
+{
+    fn default() -> Self
+    {
+        SparseGrid { columns: HashMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T>
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, (x, y): Point, value: T) -> Option<T>
+    {
+        self.columns.entry(x).or_default().insert(y, value)
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T>
+    {
+        self.columns.get(&x)?.get(&y)
+    }
+
+    pub fn contains(&self, point: Point) -> bool
+    {
+        self.get(point).is_some()
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.columns.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.columns.values().all(BTreeMap::is_empty)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)>
+    {
+        self.columns.iter().flat_map(|(&x, column)| column.iter().map(move |(&y, value)| ((x, y), value)))
+    }
+
+    /// The closest occupied cell strictly below `(x, y)`.
+    pub fn next_below(&self, (x, y): Point) -> Option<(i64, &T)>
+    {
+        self.columns.get(&x)?.range((Bound::Excluded(y), Bound::Unbounded)).next().map(|(y, value)| (*y, value))
+    }
+
+    /// `(top left, bottom right)` corners of the occupied area.
+    pub fn bounding_box(&self) -> Option<(Point, Point)>
+    {
+        self.iter().fold(None, |bbox, ((x, y), _)| match bbox
+        {
+            None => Some(((x, y), (x, y))),
+            Some(((left, top), (right, bottom))) => Some(((left.min(x), top.min(y)), (right.max(x), bottom.max(y)))),
+        })
+    }
+
+    /// Copies the occupied area into a dense grid, `empty` fills the free cells.
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some(((left, top), (right, bottom))) = self.bounding_box() else
+        {
+            return Grid::new(0, 0, empty);
+        };
+        let mut grid = Grid::new((bottom - top + 1) as usize, (right - left + 1) as usize, empty);
+        for ((x, y), value) in self.iter()
+        {
+            grid[((y - top) as usize, (x - left) as usize)] = value.clone();
+        }
+        grid
+    }
+
+    /// One character per cell of the occupied area, `empty` for the free ones, rows separated by `\n`.
+    pub fn render(&self, empty: char, symbol: impl Fn(&T) -> char) -> String
+    {
+        match self.bounding_box()
+        {
+            Some(area) => self.render_area(area, empty, symbol),
+            None => String::new(),
+        }
+    }
+
+    /// Same as [`SparseGrid::render`] for the cells between the `(top left, bottom right)` corners.
+    pub fn render_area(&self, ((left, top), (right, bottom)): (Point, Point), empty: char, symbol: impl Fn(&T) -> char) -> String
+    {
+        (top..=bottom)
+            .map(|y| (left..=right).map(|x| self.get((x, y)).map_or(empty, &symbol)).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SparseGrid<T>
+{
+    /// Renders the occupied area with `.` for the free cells.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Some(((left, top), (right, bottom))) = self.bounding_box() else
+        {
+            return Ok(());
+        };
+        for y in top..=bottom
+        {
+            if y > top
+            {
+                writeln!(f)?;
+            }
+            for x in left..=right
+            {
+                match self.get((x, y))
+                {
+                    Some(value) => write!(f, "{}", value)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
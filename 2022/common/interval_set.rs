@@ -0,0 +1,246 @@
+use std::fmt;
+
+/// Integer-like bounds of an [`IntervalSet`].
+pub trait Discrete: Copy + Ord + fmt::Debug
+{
+    /// The next value, saturating at the maximum.
+    fn successor(self) -> Self;
+    /// The previous value, saturating at the minimum.
+    fn predecessor(self) -> Self;
+    /// Number of values in `start..=end`, `end` has to be at least `start`.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t
+            {
+                fn successor(self) -> Self
+                {
+                    self.saturating_add(1)
+                }
+
+                fn predecessor(self) -> Self
+                {
+                    self.saturating_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u64
+                {
+                    (end as i128 - start as i128 + 1) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i32, i64, u32, u64, usize);
+
+/// Set of integers stored as sorted, disjoint, non-adjacent inclusive intervals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T>
+{
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T>
+{
+    fn default() -> Self
+    {
+        IntervalSet { intervals: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T>
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// The set of `start..=end`, empty when `end < start`.
+    pub fn from_interval(start: T, end: T) -> Self
+    {
+        let mut set = Self::new();
+        set.insert(start, end);
+        set
+    }
+
+    /// Adds `start..=end`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, start: T, end: T)
+    {
+        if end < start
+        {
+            return;
+        }
+        // Intervals in `first..last` overlap or touch the new one.
+        let first = self.intervals.partition_point(|&(_, other_end)| other_end.successor() < start);
+        let last = self.intervals.partition_point(|&(other_start, _)| other_start <= end.successor());
+        let merged = if first < last
+        {
+            (start.min(self.intervals[first].0), end.max(self.intervals[last - 1].1))
+        }
+        else
+        {
+            (start, end)
+        };
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Takes `start..=end` out of the set.
+    pub fn remove(&mut self, start: T, end: T)
+    {
+        if end < start
+        {
+            return;
+        }
+        let first = self.intervals.partition_point(|&(_, other_end)| other_end < start);
+        let last = self.intervals.partition_point(|&(other_start, _)| other_start <= end);
+        let mut remainder = Vec::new();
+        if first < last
+        {
+            let (first_start, _) = self.intervals[first];
+            let (_, last_end) = self.intervals[last - 1];
+            if first_start < start
+            {
+                remainder.push((first_start, start.predecessor()));
+            }
+            if end < last_end
+            {
+                remainder.push((end.successor(), last_end));
+            }
+        }
+        self.intervals.splice(first..last, remainder);
+    }
+
+    /// Empties the set, keeping its memory for reuse.
+    pub fn clear(&mut self)
+    {
+        self.intervals.clear();
+    }
+
+    /// Adds every interval of `other`.
+    pub fn merge(&mut self, other: &Self)
+    {
+        for &(start, end) in &other.intervals
+        {
+            self.insert(start, end);
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self
+    {
+        let mut result = self.clone();
+        result.merge(other);
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self
+    {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len()
+        {
+            let (start, end) = self.intervals[i];
+            let (other_start, other_end) = other.intervals[j];
+            let (common_start, common_end) = (start.max(other_start), end.min(other_end));
+            if common_start <= common_end
+            {
+                intervals.push((common_start, common_end));
+            }
+            if end < other_end
+            {
+                i += 1;
+            }
+            else
+            {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self
+    {
+        let mut result = self.clone();
+        for &(start, end) in &other.intervals
+        {
+            result.remove(start, end);
+        }
+        result
+    }
+
+    pub fn contains(&self, value: T) -> bool
+    {
+        let index = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals.get(index).is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool
+    {
+        self.difference(other).is_empty()
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool
+    {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u64
+    {
+        self.intervals.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    /// The disjoint intervals in increasing order.
+    pub fn intervals(&self) -> impl Iterator<Item = (T, T)> + '_
+    {
+        self.intervals.iter().copied()
+    }
+
+    /// Intervals between consecutive intervals of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = (T, T)> + '_
+    {
+        self.intervals.windows(2).map(|pair| (pair[0].1.successor(), pair[1].0.predecessor()))
+    }
+
+    /// The smallest value of `start..=end` that is not in the set, found without allocating.
+    pub fn first_gap_within(&self, start: T, end: T) -> Option<T>
+    {
+        if end < start
+        {
+            return None;
+        }
+        let index = self.intervals.partition_point(|&(_, other_end)| other_end < start);
+        match self.intervals.get(index)
+        {
+            Some(&(other_start, other_end)) if other_start <= start => (other_end < end).then(|| other_end.successor()),
+            _ => Some(start),
+        }
+    }
+
+    /// Values of `start..=end` that are not in the set, as intervals.
+    pub fn gaps_within(&self, start: T, end: T) -> impl Iterator<Item = (T, T)>
+    {
+        IntervalSet::from_interval(start, end).difference(self).intervals.into_iter()
+    }
+}
+
+impl<T: Discrete> FromIterator<(T, T)> for IntervalSet<T>
+{
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self
+    {
+        let mut set = Self::new();
+        for (start, end) in iter
+        {
+            set.insert(start, end);
+        }
+        set
+    }
+}
@@ -0,0 +1,5 @@
+//! Building blocks shared by several days.
+
+pub mod grid;
+pub mod interval_set;
+pub mod search;
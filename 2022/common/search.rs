@@ -0,0 +1,160 @@
+//! Shortest paths over graphs given by a successor function.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Costs of the edges; `Default` is the zero cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Distances from the sources to every reached node and the predecessor of each node on a shortest path.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C>
+{
+    pub distances: HashMap<N, C>,
+    /// The sources have no predecessor.
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C>
+{
+    fn new(sources: &[N]) -> Self
+        where C: Default
+    {
+        let distances = sources.iter().map(|source| (source.clone(), C::default())).collect();
+        SearchResult { distances, predecessors: HashMap::new() }
+    }
+
+    /// `None` when `node` wasn't reached.
+    pub fn distance(&self, node: &N) -> Option<C>
+    {
+        self.distances.get(node).copied()
+    }
+
+    /// Nodes of a shortest path from one of the sources to `target`, both included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>>
+    {
+        if !self.distances.contains_key(target)
+        {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap())
+        {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search where every edge costs 1.
+pub fn bfs<N, I>(sources: impl IntoIterator<Item = N>, successors: impl Fn(&N) -> I) -> SearchResult<N, usize>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item = N>
+{
+    // Keeps the order of the sources so that ties are broken the same way on every run.
+    let sources: Vec<N> = sources.into_iter().collect();
+    let mut result = SearchResult::new(&sources);
+    let mut queue = VecDeque::from(sources);
+    while let Some(node) = queue.pop_front()
+    {
+        let distance = result.distances[&node] + 1;
+        for successor in successors(&node)
+        {
+            if !result.distances.contains_key(&successor)
+            {
+                result.distances.insert(successor.clone(), distance);
+                result.predecessors.insert(successor.clone(), node.clone());
+                queue.push_back(successor);
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's algorithm, the costs can't be negative.
+pub fn dijkstra<N, C, I>(sources: impl IntoIterator<Item = N>, successors: impl Fn(&N) -> I) -> SearchResult<N, C>
+    where N: Clone + Eq + Hash, C: Cost, I: IntoIterator<Item = (N, C)>
+{
+    best_first(sources, None, successors, |_| C::default())
+}
+
+/// A* search towards `target`, which stops once the distance to it is known.
+///
+/// `heuristic` must never overestimate the cost to `target` and must be consistent, i.e. drop by at most the cost
+/// of every edge.
+pub fn a_star<N, C, I>(sources: impl IntoIterator<Item = N>, target: &N, successors: impl Fn(&N) -> I, heuristic: impl Fn(&N) -> C) -> SearchResult<N, C>
+    where N: Clone + Eq + Hash, C: Cost, I: IntoIterator<Item = (N, C)>
+{
+    best_first(sources, Some(target), successors, heuristic)
+}
+
+/// Entry of the priority queue, ordered so that the lowest estimate comes out of the max-heap first.
+struct Queued<N, C>
+{
+    estimate: C,
+    distance: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+fn best_first<N, C, I>(sources: impl IntoIterator<Item = N>, target: Option<&N>, successors: impl Fn(&N) -> I, heuristic: impl Fn(&N) -> C) -> SearchResult<N, C>
+    where N: Clone + Eq + Hash, C: Cost, I: IntoIterator<Item = (N, C)>
+{
+    let sources: Vec<N> = sources.into_iter().collect();
+    let mut result = SearchResult::new(&sources);
+    let mut queue: BinaryHeap<Queued<N, C>> = sources.into_iter()
+        .map(|source| Queued { estimate: heuristic(&source), distance: C::default(), node: source })
+        .collect();
+    while let Some(Queued { distance, node, .. }) = queue.pop()
+    {
+        if distance > result.distances[&node]
+        {
+            // A shorter way to the node was found after this entry was queued.
+            continue;
+        }
+        if target == Some(&node)
+        {
+            break;
+        }
+        for (successor, cost) in successors(&node)
+        {
+            let successor_distance = distance + cost;
+            if result.distances.get(&successor).is_none_or(|&known| successor_distance < known)
+            {
+                result.distances.insert(successor.clone(), successor_distance);
+                result.predecessors.insert(successor.clone(), node.clone());
+                queue.push(Queued { estimate: successor_distance + heuristic(&successor), distance: successor_distance, node: successor });
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,38 @@
+use std::collections::BinaryHeap;
+
+use crate::error::{numbered_blocks, numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Calories carried by each elf.
+    type Input = Vec<i32>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        numbered_blocks(contents)
+            .map(|(first_line, block)| numbered_lines(block).try_fold(0i32, |acc, (index, x)| {
+                let number = first_line + index - 1;
+                let calories = x.trim().parse::<i32>()
+                    .map_err(|_| ParseError::new(number, 1, x, "expected a number of calories"))?;
+                acc.checked_add(calories)
+                    .ok_or_else(|| ParseError::new(number, 1, x, "the elf carries too many calories"))
+            }))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        let mut max: i32 = 0;
+        for &sum in input {
+            if sum > max{
+                max = sum;
+            }
+        }
+        max.into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        let mut heap: BinaryHeap<i32> = input.iter().copied().collect();
+        (0..3).map(|_| heap.pop().map_or(0, i64::from)).sum::<i64>().into()
+    }
+}
@@ -0,0 +1,79 @@
+use itertools::Itertools;
+
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+fn cast_options(choice: &str)-> i32 {
+    match choice{
+            "X" => 0,
+            "Y" => 1,
+            "Z" => 2,
+            "A" => 0,
+            "B" => 1,
+            "C" => 2,
+            _ => 0
+        }
+}
+
+fn cast_outcome(choice: &str)-> i32 {
+    match choice{
+            "X" => 0,
+            "Y" => 3,
+            "Z" => 6,
+            _ => 0
+        }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// Both columns of the strategy guide as written.
+    type Input = Vec<(String, String)>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(contents)
+            .map(|(number, l)| match l.split(" ").next_tuple() {
+                Some((elf @ ("A" | "B" | "C"), me @ ("X" | "Y" | "Z"))) => Ok((elf.to_string(), me.to_string())),
+                _ => Err(ParseError::new(number, 1, l, "expected `A|B|C X|Y|Z`")),
+            })
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        let mut sum: i32 = 0;
+        for (elf_choice_input, my_choice_input) in input {
+            let elf_choice = cast_options(elf_choice_input);
+            let my_choice = cast_options(my_choice_input);
+            let s1 = my_choice +1;
+            let s2 = match (my_choice - elf_choice + 3) % 3
+            {
+                1 => 6,
+                0 => 3,
+                2 => 0,
+                _ => 0
+            };
+            let round_score = s1 + s2;
+            sum += round_score;
+        }
+        sum.into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        let mut sum: i32 = 0;
+        for (elf_choice_input, outcome_input) in input {
+            let elf_choice = cast_options(elf_choice_input);
+            let outcome = cast_outcome(outcome_input);
+            let shift = match outcome
+            {
+                6 => 1,
+                3 => 0,
+                0 => 2,
+                _ => 0
+            };
+            let my_choice = (elf_choice + shift) % 3;
+            let round_score = outcome + my_choice + 1;
+            sum += round_score;
+        }
+        sum.into()
+    }
+}
@@ -0,0 +1,60 @@
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+fn cast_priority_to_int(letter: char) -> u32
+{
+    if letter.is_lowercase()
+    {
+        return u32::from(letter) - u32::from('a') + 1;
+    }
+    if letter.is_uppercase()
+    {
+        return u32::from(letter) - u32::from('A') + 27;
+    }
+    0
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// Contents of each rucksack.
+    type Input = Vec<String>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(contents.split("\n").filter(|x| !x.is_empty()).map(String::from).collect())
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+        for l in input {
+            let rucksack_left = l.get(0..l.len()/2);
+            let rucksack_right = l.get(l.len()/2 .. l.len());
+            if let Some(rucksack_left_str) = rucksack_left {
+                if let Some(rucksack_right_str) = rucksack_right {
+                    let mut priority_iter = rucksack_left_str.chars().filter(|letter| rucksack_right_str.contains(*letter));
+                    let priority_opt = priority_iter.next();
+                    if let Some(priority) = priority_opt {
+                        sum += cast_priority_to_int(priority);
+                    }
+                }
+            }
+        }
+        sum.into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+        for (first_rucksack, second_rucksack, third_rucksack) in input.iter().tuples()
+        {
+            let mut priority_iter  = first_rucksack.chars().filter(|letter| second_rucksack.contains(*letter))
+                .filter(|letter| third_rucksack.contains(*letter));
+            let priority_opt = priority_iter.next();
+            if let Some(priority) = priority_opt {
+                sum += cast_priority_to_int(priority);
+            }
+        }
+        sum.into()
+    }
+}
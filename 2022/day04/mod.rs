@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::common::interval_set::IntervalSet;
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+type Sections = IntervalSet<u32>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// Section assignments of each pair of elves.
+    type Input = Vec<(Sections, Sections)>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let mut pairs = Vec::new();
+        for (number, line) in numbered_lines(contents){
+            let error = || ParseError::new(number, 1, line, "expected `a-b,c-d`");
+            let (first_range, second_range) = line.split(",").next_tuple().ok_or_else(error)?;
+            let first_borders: (&str, &str) = first_range.split("-").next_tuple().ok_or_else(error)?;
+            let second_borders: (&str, &str) = second_range.split("-").next_tuple().ok_or_else(error)?;
+            let parse_border = |x: &str| x.parse::<u32>()
+                .map_err(|_| ParseError::at(number, line, x, "expected a section number"));
+            pairs.push((Sections::from_interval(parse_border(first_borders.0)?, parse_border(first_borders.1)?),
+                Sections::from_interval(parse_border(second_borders.0)?, parse_border(second_borders.1)?)));
+        }
+        Ok(pairs)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+        for (first_sections, second_sections) in input {
+            if first_sections.is_subset(second_sections) || second_sections.is_subset(first_sections)
+            {
+                sum += 1;
+            }
+        }
+        sum.into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        let mut sum: u32 = 0;
+        for (first_sections, second_sections) in input {
+            if first_sections.intersects(second_sections)
+            {
+                sum += 1;
+            }
+        }
+        sum.into()
+    }
+}
@@ -0,0 +1,127 @@
+use itertools::Itertools;
+use regex::Regex;
+use tuple_map::*;
+
+use crate::error::{numbered_blocks, numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+type Containers = Vec<Vec<char>>;
+
+/// Reads the drawing of the stacks, which starts at `first_line` of the input and ends with their labels.
+fn initialize_scheme(first_line: usize, scheme: & str) -> Result<Containers, ParseError>
+{
+    let labels = scheme.lines().last().unwrap_or_default();
+    let vec_size = labels.split(' ').rev().find(|x| !x.is_empty());
+    let mut result: Vec<Vec<char>> = Vec::new();
+    if let Some(size) = vec_size
+    {
+        let size = size.parse::<usize>().map_err(|_|
+        {
+            let number = first_line + scheme.lines().count() - 1;
+            ParseError::at(number, labels, size, "expected the number of the last stack")
+        })?;
+        result.resize_with(size, Default::default);
+        for line in scheme.split('\n').rev().skip(1)
+        {
+            let line_of_chars: Vec<_> = line.chars().collect();
+            for (i, stack) in result.iter_mut().enumerate()
+            {
+                let position = i * 4 + 1;
+                if let Some(&container) = line_of_chars.get(position).filter(|&&x| x != ' ')
+                {
+                    stack.push(container);
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn top_crates(container_positions: &Containers) -> String
+{
+    container_positions.iter().map(|x| if x.is_empty() {' '} else { x[x.len()-1] }).join("")
+}
+
+fn apply_action(container_positions: &mut Containers, amount: usize, from: usize, to: usize)
+{
+    for _i in 0..amount
+    {
+        if let Some(item) = container_positions[from].pop(){
+            container_positions[to].push(item);   
+        }
+    }
+}
+
+fn apply_action_2(container_positions: &mut Containers, amount: usize, from: usize, to: usize)
+{
+    let first_elem = container_positions[from].len() - amount;
+    let from_iter: Vec<_> = container_positions[from].drain(first_elem ..).collect();
+    container_positions[to].extend(from_iter);
+}
+
+/// How many crates to move, and the zero-based stacks to move them between.
+type Action = (usize, usize, usize);
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Containers, Vec<Action>);
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let mut parsed_actions = Vec::new();
+        let split_input: Option<(_,_)> = numbered_blocks(contents).next_tuple();
+        let Some(((scheme_line, scheme), (first_line, actions))) = split_input else {
+            return Err(ParseError::new(1, 1, contents.lines().next().unwrap_or_default(),
+                "expected the drawing of the stacks and the procedure separated by an empty line"));
+        };
+        let container_positions = initialize_scheme(scheme_line, scheme)?;
+        // The moves can't take more crates than a stack holds at that point of the procedure.
+        let mut heights: Vec<usize> = container_positions.iter().map(Vec::len).collect();
+        let re = Regex::new(r"^move (?<number>[0-9]+) from (?<from>[0-9]+) to (?<to>[0-9]+)$").unwrap();
+        for (index, line) in numbered_lines(actions)
+        {
+            let number = first_line + index - 1;
+            let Some(values) = re.captures(line) else {
+                return Err(ParseError::new(number, 1, line, "expected `move N from A to B`"));
+            };
+            let (amount, from, to) = (&values["number"], &values["from"], &values["to"])
+                .map(|x| x.parse::<usize>().map_err(|_| ParseError::at(number, line, x, "number is too large")));
+            let (amount, from, to) = (amount?, from?, to?);
+            for (stack, name) in [(from, "from"), (to, "to")]
+            {
+                if !(1..=container_positions.len()).contains(&stack)
+                {
+                    let column = values.name(name).unwrap().start() + 1;
+                    return Err(ParseError::new(number, column, line, format!("there is no stack {}", stack)));
+                }
+            }
+            if amount > heights[from - 1]
+            {
+                return Err(ParseError::at(number, line, &values["number"],
+                    format!("stack {} only holds {} crates at this point", from, heights[from - 1])));
+            }
+            heights[from - 1] -= amount;
+            heights[to - 1] += amount;
+            parsed_actions.push((amount, from - 1, to - 1));
+        }
+        Ok((container_positions, parsed_actions))
+    }
+
+    fn part_1(&self, (containers, actions): &Self::Input) -> Answer {
+        let mut container_positions = containers.clone();
+        for &(number, from, to) in actions
+        {
+            apply_action(&mut container_positions, number, from, to);
+        }
+        top_crates(&container_positions).into()
+    }
+
+    fn part_2(&self, (containers, actions): &Self::Input) -> Answer {
+        let mut container_positions = containers.clone();
+        for &(number, from, to) in actions
+        {
+            apply_action_2(&mut container_positions, number, from, to);
+        }
+        top_crates(&container_positions).into()
+    }
+}
@@ -1,24 +1,14 @@
-use std::fs;
 use std::collections::{BTreeMap, VecDeque};
 
-const FILE_PATH: &str = "day06/input.txt";
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
 
 type Marker = BTreeMap<char, i32>;
 type Window = VecDeque<char>;
 
-
-
 fn add_symbol_to_state(window: &mut Window, marker: &mut Marker, symbol: char){
     window.push_back(symbol);
-    if marker.contains_key(&symbol)
-    {
-        if let Some(symbol) = marker.get_mut(&symbol){
-              *symbol += 1;
-            }
-    }
-    else {
-        marker.insert(symbol, 1);
-    }
+    *marker.entry(symbol).or_insert(0) += 1;
 }
 
 fn delete_symbol_from_state(window: &mut Window, marker: &mut Marker){
@@ -37,7 +27,7 @@ fn delete_symbol_from_state(window: &mut Window, marker: &mut Marker){
     }
 }
 
-fn algorithm(contents: & String, window_size: usize){
+fn algorithm(contents: &str, window_size: usize) -> i32 {
     let mut sliding_window = Window::new();
     let mut marker = Marker::new();
     let mut start_of_message: i32 = 0;
@@ -49,7 +39,7 @@ fn algorithm(contents: & String, window_size: usize){
         start_of_message += 1;
     }
     
-    while let Some(letter) = str_iter.next() {
+    for letter in str_iter {
         if marker.len() == window_size{
             break;
         }
@@ -57,21 +47,24 @@ fn algorithm(contents: & String, window_size: usize){
         add_symbol_to_state(& mut sliding_window, & mut marker, letter);
         start_of_message += 1;
     }
-    println!("{}", start_of_message);
+    start_of_message
 }
 
-fn part_1(contents: & String){
-    algorithm(contents, 4);
-}
+pub struct Day06;
 
-fn part_2(contents: & String) {
-    algorithm(contents, 14);
-}
+impl Solution for Day06 {
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(contents.trim().to_string())
+    }
 
-fn main() {
-    let contents = fs::read_to_string(FILE_PATH)
-        .expect("Should have been able to read the file");
+    fn part_1(&self, input: &Self::Input) -> Answer {
+        algorithm(input, 4).into()
+    }
 
-    part_1(&contents);
-    part_2(&contents);
-}
\ No newline at end of file
+    fn part_2(&self, input: &Self::Input) -> Answer {
+        algorithm(input, 14).into()
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+const TOTAL_SPACE: u64 = 70000000;
+const REQUIRED_SPACE: u64 = 30000000;
+const SMALL_DIRECTORY_SIZE: u64 = 100000;
+
+type NodeId = usize;
+const ROOT: NodeId = 0;
+
+#[derive(Debug)]
+enum NodeKind
+{
+    /// Children by name, so that listing a directory twice doesn't count it twice.
+    Directory(BTreeMap<String, NodeId>),
+    File,
+}
+
+#[derive(Debug)]
+struct Node
+{
+    name: String,
+    parent: Option<NodeId>,
+    /// Own size for files, cumulative size for directories.
+    size: u64,
+    kind: NodeKind,
+}
+
+/// Filesystem reconstructed from the terminal transcript, the nodes refer to each other by index.
+#[derive(Debug)]
+pub struct FileSystem
+{
+    nodes: Vec<Node>,
+}
+
+impl FileSystem
+{
+    fn new() -> Self
+    {
+        FileSystem { nodes: vec![Node { name: "/".to_string(), parent: None, size: 0, kind: NodeKind::Directory(BTreeMap::new()) }] }
+    }
+
+    fn child(&self, directory: NodeId, name: &str) -> Option<NodeId>
+    {
+        match &self.nodes[directory].kind
+        {
+            NodeKind::Directory(children) => children.get(name).copied(),
+            NodeKind::File => None,
+        }
+    }
+
+    /// Returns the existing node when `name` was already listed.
+    fn add(&mut self, directory: NodeId, name: &str, size: Option<u64>) -> NodeId
+    {
+        if let Some(existing) = self.child(directory, name)
+        {
+            return existing;
+        }
+        let id = self.nodes.len();
+        let kind = match size
+        {
+            Some(_) => NodeKind::File,
+            None => NodeKind::Directory(BTreeMap::new()),
+        };
+        self.nodes.push(Node { name: name.to_string(), parent: Some(directory), size: size.unwrap_or(0), kind });
+        if let NodeKind::Directory(children) = &mut self.nodes[directory].kind
+        {
+            children.insert(name.to_string(), id);
+        }
+        let mut ancestor = Some(directory);
+        while let Some(current) = ancestor
+        {
+            self.nodes[current].size += size.unwrap_or(0);
+            ancestor = self.nodes[current].parent;
+        }
+        id
+    }
+
+    fn is_directory(&self, node: NodeId) -> bool
+    {
+        matches!(self.nodes[node].kind, NodeKind::Directory(_))
+    }
+
+    pub fn used_space(&self) -> u64
+    {
+        self.nodes[ROOT].size
+    }
+
+    /// Cumulative sizes of all directories, the root included.
+    pub fn directory_sizes(&self) -> impl Iterator<Item = u64> + '_
+    {
+        (0..self.nodes.len()).filter(|&node| self.is_directory(node)).map(|node| self.nodes[node].size)
+    }
+
+    /// Renders the filesystem like `tree(1)`, with the size of every file and directory.
+    pub fn render_tree(&self) -> String
+    {
+        let mut lines = vec![format!("{} ({})", self.nodes[ROOT].name, self.nodes[ROOT].size)];
+        self.render_children(ROOT, "", &mut lines);
+        lines.join("\n")
+    }
+
+    fn render_children(&self, directory: NodeId, prefix: &str, lines: &mut Vec<String>)
+    {
+        let NodeKind::Directory(children) = &self.nodes[directory].kind else
+        {
+            return;
+        };
+        for (index, &child) in children.values().enumerate()
+        {
+            let is_last = index + 1 == children.len();
+            let node = &self.nodes[child];
+            let (branch, indent) = if is_last { ("└── ", "    ") } else { ("├── ", "│   ") };
+            let suffix = if self.is_directory(child) { "/" } else { "" };
+            lines.push(format!("{}{}{}{} ({})", prefix, branch, node.name, suffix, node.size));
+            self.render_children(child, &format!("{}{}", prefix, indent), lines);
+        }
+    }
+}
+
+fn read_input(contents: &str) -> Result<FileSystem, ParseError>
+{
+    let mut file_system = FileSystem::new();
+    let mut current = ROOT;
+    let mut listing = false;
+    for (number, line) in numbered_lines(contents)
+    {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..]
+        {
+            ["$", "cd", "/"] => current = ROOT,
+            ["$", "cd", ".."] =>
+            {
+                current = file_system.nodes[current].parent
+                    .ok_or_else(|| ParseError::at(number, line, words[2], "the root directory has no parent"))?;
+            },
+            ["$", "cd", name] =>
+            {
+                current = match file_system.child(current, name)
+                {
+                    Some(child) if !file_system.is_directory(child) => return Err(ParseError::at(number, line, name, "not a directory")),
+                    Some(child) => child,
+                    None => file_system.add(current, name, None),
+                };
+            },
+            ["$", "ls"] => listing = true,
+            ["$", ..] => return Err(ParseError::new(number, 3, line, "unknown command, expected `cd` or `ls`")),
+            _ if !listing => return Err(ParseError::new(number, 1, line, "expected a command starting with `$`")),
+            ["dir", name] =>
+            {
+                file_system.add(current, name, None);
+            },
+            [size, name] =>
+            {
+                let size = size.parse::<u64>().map_err(|_| ParseError::new(number, 1, line, "expected `dir` or a file size"))?;
+                file_system.add(current, name, Some(size));
+            },
+            _ => return Err(ParseError::new(number, 1, line, "expected `dir NAME` or `SIZE NAME`")),
+        }
+        if words.first() == Some(&"$") && words.get(1) != Some(&"ls")
+        {
+            listing = false;
+        }
+    }
+    Ok(file_system)
+}
+
+pub struct Day07;
+
+impl Solution for Day07
+{
+    type Input = FileSystem;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        input.directory_sizes().filter(|&size| size <= SMALL_DIRECTORY_SIZE).sum::<u64>().into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        let missing_space = (input.used_space() + REQUIRED_SPACE).saturating_sub(TOTAL_SPACE);
+        input.directory_sizes().filter(|&size| size >= missing_space).min().unwrap_or(0).into()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::common::grid::{Grid, Rotation};
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+/// Heights of the trees.
+pub type Input = Grid<i32>;
+
+fn read_input(contents: &str) -> Result<Input, ParseError>
+{
+    Grid::parse(contents, "a tree height", |ch| ch.to_digit(10).map(|height| height as i32))
+}
+
+fn is_visible_from_edge(elem: i32, max_from_edge_side: i32) -> bool
+{
+    elem > max_from_edge_side
+}
+
+fn process_visibility_by_rotation(input: &Input, result: &mut Grid<bool>, rotation: Rotation)
+{
+    let view = input.rotated(rotation);
+    let mut max_front: Vec<i32> = vec![-1; view.columns()];
+    for row in 0..view.rows()
+    {
+        for (column, max_in_column) in max_front.iter_mut().enumerate()
+        {
+            let map_item = view[(row, column)];
+            if  is_visible_from_edge(map_item, *max_in_column) {
+                result[view.original((row, column))] = true;
+                *max_in_column = map_item;
+            }
+        }
+    }
+}
+
+fn calculate_visibility(input: &Input) -> Grid<bool> {
+    let mut result = Grid::new(input.rows(), input.columns(), false);
+    for rotation in Rotation::ALL
+    {
+        process_visibility_by_rotation(input, &mut result, rotation);
+    }
+
+    result
+}
+
+fn count_visible_trees(input: &Input) -> i32
+{
+    let visibility_map = calculate_visibility(input);
+    visibility_map.iter().filter(|(_, visible)| **visible).count() as i32
+}
+
+#[derive(Clone, Copy)]
+enum Direction
+{
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+fn count_visible_trees_in_line<'a>(tree_height: i32, line: impl Iterator<Item = &'a i32>) -> i32
+{
+    let mut scentic_score: i32 = 0;
+    for &other_height in line
+    {
+        scentic_score += 1;
+        if tree_height <= other_height
+        {
+            break;
+        }
+    }
+    scentic_score
+}
+
+fn calculate_scentic_score(input: &Input, row: usize, column: usize, direction: Direction) -> i32 {
+    let tree_height = input[(row, column)];
+    match direction
+        {
+            Direction::Left => count_visible_trees_in_line(tree_height, input.row(row)[..column].iter().rev()),
+            Direction::Right => count_visible_trees_in_line(tree_height, input.row(row)[column + 1..].iter()),
+            Direction::Top => count_visible_trees_in_line(tree_height, input.column(column).take(row).rev()),
+            Direction::Bottom => count_visible_trees_in_line(tree_height, input.column(column).skip(row + 1)),
+        }
+}
+
+fn tree_is_on_the_edge(input: &Input, row: usize, column: usize) -> bool
+{
+    row == 0 || row == input.rows() - 1 || column == 0 || column == input.columns() - 1
+}
+
+fn find_highest_scentic_score(input: &Input) -> i32
+{
+    let mut max_scentic_score: i32 = 0;
+
+    for row in 0..input.rows()
+    {
+        for column in 0..input.columns()
+        {
+            if !tree_is_on_the_edge(input, row, column) {
+                let current_scentic_score = [Direction::Left, Direction::Right, Direction::Top, Direction::Bottom].iter().fold(
+                    1, |score, &direction| score * calculate_scentic_score(input, row, column, direction));
+                if max_scentic_score < current_scentic_score
+                {
+                    max_scentic_score = current_scentic_score;
+                }
+            }
+        }
+    }
+
+    max_scentic_score
+}
+
+pub struct Day08;
+
+impl Solution for Day08
+{
+    type Input = Input;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        count_visible_trees(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        find_highest_scentic_score(input).into()
+    }
+}
@@ -1,8 +1,9 @@
 use itertools::Itertools;
-use std::{fs, vec};
+use std::vec;
 use std::collections::HashSet;
 
-const FILE_PATH: &str = "day09/input.txt";
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
 
 #[derive(Clone, Copy, Debug)]
 enum Direction
@@ -14,7 +15,7 @@ enum Direction
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Instruction
+pub struct Instruction
 {
     direction: Direction,
     number_of_steps: usize
@@ -37,11 +38,11 @@ fn calculate_head_position(position: (i32, i32), direction: & Direction) -> (i32
     {
         match direction
         {
-            Direction::Up => return (position.0, position.1 + 1),
-            Direction::Right => return (position.0 + 1, position.1),
-            Direction::Down => return (position.0, position.1 - 1),
-            Direction::Left => return (position.0 - 1, position.1)
-        };
+            Direction::Up => (position.0, position.1 + 1),
+            Direction::Right => (position.0 + 1, position.1),
+            Direction::Down => (position.0, position.1 - 1),
+            Direction::Left => (position.0 - 1, position.1)
+        }
     }
 
 fn change_knot(previous_knot: &(i32, i32), knot: &(i32, i32)) -> Option<(i32, i32)>
@@ -53,7 +54,7 @@ fn change_knot(previous_knot: &(i32, i32), knot: &(i32, i32)) -> Option<(i32, i3
         return None;
     }
 
-    let mut new_position: (i32, i32) = knot.clone();
+    let mut new_position: (i32, i32) = *knot;
     let x_direction = if x_diff == x_diff.abs(){1} else {-1};
     let y_direction = if y_diff == y_diff.abs(){1} else {-1};
     if x_diff.abs() > 0
@@ -64,22 +65,22 @@ fn change_knot(previous_knot: &(i32, i32), knot: &(i32, i32)) -> Option<(i32, i3
     {
         new_position.1 = knot.1 + y_direction;
     }
-    return Some(new_position);
+    Some(new_position)
 }
 
 impl InstructionProcessor<'_>
 {
     fn change_rope_positions(&mut self, direction: &Direction)
     {
-        self.state.rope_position[0] = calculate_head_position(self.state.rope_position[0], &direction);
+        self.state.rope_position[0] = calculate_head_position(self.state.rope_position[0], direction);
 
         let rope_size = self.state.rope_position.len();
-        let mut tail_moved = false || rope_size == 1;
+        let mut tail_moved = rope_size == 1;
         for i in 1..rope_size
         {
             if let Some(new_position) = change_knot(&self.state.rope_position[i - 1], &self.state.rope_position[i])
             {
-                self.state.rope_position[i] = new_position.clone();
+                self.state.rope_position[i] = new_position;
                 if i == rope_size - 1
                 {
                     tail_moved = true;
@@ -105,18 +106,18 @@ impl InstructionProcessor<'_>
     }
 }
 
-fn process_input_direction(input: &str)-> Direction {
-    return match input
+fn process_input_direction(input: &str)-> Option<Direction> {
+    match input
     {
-        "U" => Direction::Up,
-        "R" => Direction::Right,
-        "D" => Direction::Down,
-        "L" => Direction::Left,
-        _ => panic!("{:?}", "Unexpected command")
-    };
+        "U" => Some(Direction::Up),
+        "R" => Some(Direction::Right),
+        "D" => Some(Direction::Down),
+        "L" => Some(Direction::Left),
+        _ => None
+    }
 }
 
-fn simulate(contents: & String, knots_number: usize) -> usize
+fn simulate(instructions: &[Instruction], knots_number: usize) -> usize
 {
     let mut state =
         State
@@ -132,40 +133,48 @@ fn simulate(contents: & String, knots_number: usize) -> usize
             state: &mut state
         };
 
-    for l in contents.split("\n").filter(|x| !x.is_empty()){
-        let (direction_input, number_of_steps_input) = l.split(" ").next_tuple().unwrap();
-        let instruction =
-            Instruction
-            {
-                direction: process_input_direction(direction_input),
-                number_of_steps: number_of_steps_input.parse::<usize>().unwrap()
-            };
-        instruction_processor.apply_instruction(&instruction);
+    for instruction in instructions
+    {
+        instruction_processor.apply_instruction(instruction);
     }
 
-    return instruction_processor.state.positions_visited_by_tail.len();
+    instruction_processor.state.positions_visited_by_tail.len()
 }
 
-fn part_1(contents: & String)
+fn read_input(contents: &str) -> Result<Vec<Instruction>, ParseError>
 {
-    println!("Part 1");
-    println!("Sum of positions visited by tail: {}", simulate(contents, 2));
+    numbered_lines(contents).map(|(number, l)|
+    {
+        let (direction_input, number_of_steps_input) = l.split(" ").next_tuple()
+            .ok_or_else(|| ParseError::new(number, 1, l, "expected a direction and a number of steps"))?;
+        Ok(Instruction
+        {
+            direction: process_input_direction(direction_input)
+                .ok_or_else(|| ParseError::at(number, l, direction_input, "unexpected command, expected one of U, R, D, L"))?,
+            number_of_steps: number_of_steps_input.parse::<usize>()
+                .map_err(|_| ParseError::at(number, l, number_of_steps_input, "expected a number of steps"))?
+        })
+    }).collect()
 }
 
-fn part_2(contents: & String)
-{
-    println!("Part 2");
-    println!("Sum of positions visited by tail: {}", simulate(contents, 10));
-}
+pub struct Day09;
 
-fn main()
+impl Solution for Day09
 {
-    let test = false;
-    let contents = if test {
-                "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2".into()
-            } else {
-                fs::read_to_string(FILE_PATH).expect("Should have been able to read the file")
-            };
-    part_1(&contents);
-    part_2(&contents);
-}
\ No newline at end of file
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        simulate(input, 2).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        simulate(input, 10).into()
+    }
+}
@@ -0,0 +1,176 @@
+//! Cycle-accurate emulator of the handheld's CPU.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{numbered_lines, ParseError};
+
+pub type Register = i64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction
+{
+    Noop,
+    Addx(Register),
+}
+
+impl Instruction
+{
+    /// Cycles the instruction takes to complete.
+    pub fn cycles(self) -> usize
+    {
+        match self
+        {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+
+    /// Applies the effect of the instruction once its last cycle is over.
+    fn execute(self, x: &mut Register)
+    {
+        match self
+        {
+            Instruction::Noop => {},
+            Instruction::Addx(value) => *x += value,
+        }
+    }
+}
+
+impl Instruction
+{
+    /// Parses one line of the program, the error comes with the part of `line` it is about.
+    fn parse(line: &str) -> Result<Self, (&str, String)>
+    {
+        let mut words = line.split_whitespace();
+        let instruction = match (words.next(), words.next())
+        {
+            (Some("noop"), None) => Instruction::Noop,
+            (Some("addx"), Some(value)) => Instruction::Addx(value.parse().map_err(|_| (value, "expected an integer after `addx`".to_string()))?),
+            (Some("addx"), None) => return Err((&line[line.len()..], "expected an integer after `addx`".to_string())),
+            (first, _) => return Err((first.unwrap_or(line), "expected `noop` or `addx <integer>`".to_string())),
+        };
+        match words.next()
+        {
+            None => Ok(instruction),
+            Some(operand) => Err((operand, "unexpected operand".to_string())),
+        }
+    }
+}
+
+impl FromStr for Instruction
+{
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err>
+    {
+        Instruction::parse(line).map_err(|(_, message)| message)
+    }
+}
+
+impl fmt::Display for Instruction
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::Addx(value) => write!(f, "addx {}", value),
+        }
+    }
+}
+
+pub fn parse_program(contents: &str) -> Result<Vec<Instruction>, ParseError>
+{
+    numbered_lines(contents)
+        .map(|(number, line)| Instruction::parse(line).map_err(|(token, message)| ParseError::at(number, line, token, message)))
+        .collect()
+}
+
+/// What the CPU is doing during a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State
+{
+    /// 1-based number of the cycle.
+    pub cycle: usize,
+    /// Value of the X register during the cycle.
+    pub x: Register,
+    /// Instruction being executed.
+    pub instruction: Instruction,
+}
+
+impl fmt::Display for State
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "cycle {:>4}  x = {:>4}  {}", self.cycle, self.x, self.instruction)
+    }
+}
+
+/// Iterates over the cycles of the program, one [`State`] per cycle.
+#[derive(Debug, Clone)]
+pub struct Cpu<'a>
+{
+    program: &'a [Instruction],
+    /// Index of the current instruction.
+    pointer: usize,
+    /// Cycles the current instruction still needs, 0 before it starts.
+    remaining_cycles: usize,
+    cycle: usize,
+    x: Register,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl<'a> Cpu<'a>
+{
+    pub fn new(program: &'a [Instruction]) -> Self
+    {
+        Cpu { program, pointer: 0, remaining_cycles: 0, cycle: 0, x: 1, breakpoints: BTreeSet::new() }
+    }
+
+    /// Makes [`Cpu::resume`] stop during the given cycle.
+    pub fn with_breakpoints(mut self, cycles: impl IntoIterator<Item = usize>) -> Self
+    {
+        self.breakpoints.extend(cycles);
+        self
+    }
+
+    /// Runs until a cycle with a breakpoint and returns it, `None` once the program is over.
+    pub fn resume(&mut self) -> Option<State>
+    {
+        let breakpoints = std::mem::take(&mut self.breakpoints);
+        let state = self.find(|state| breakpoints.contains(&state.cycle));
+        self.breakpoints = breakpoints;
+        state
+    }
+
+    /// One line per cycle of the whole program.
+    pub fn trace(program: &[Instruction]) -> String
+    {
+        Cpu::new(program).map(|state| state.to_string()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl Iterator for Cpu<'_>
+{
+    type Item = State;
+
+    fn next(&mut self) -> Option<State>
+    {
+        let instruction = *self.program.get(self.pointer)?;
+        if self.remaining_cycles == 0
+        {
+            self.remaining_cycles = instruction.cycles();
+        }
+        self.cycle += 1;
+        let state = State { cycle: self.cycle, x: self.x, instruction };
+        self.remaining_cycles -= 1;
+        if self.remaining_cycles == 0
+        {
+            instruction.execute(&mut self.x);
+            self.pointer += 1;
+        }
+        Some(state)
+    }
+}
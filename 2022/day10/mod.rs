@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+pub mod cpu;
+pub mod ocr;
+
+use crate::common::grid::Grid;
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+use cpu::{Cpu, Instruction, Register};
+
+fn calculate_sum_of_signal_strengths(program: &[Instruction], cycle_numbers: &HashSet<usize>) -> Register
+{
+    Cpu::new(program)
+        .filter(|state| cycle_numbers.contains(&state.cycle))
+        .map(|state| state.cycle as Register * state.x)
+        .sum()
+}
+
+fn determine_symbol(sprite_position: Register, cycle_number: usize, width: usize) -> bool
+{
+    let pixel_position = ((cycle_number - 1) % width) as Register;
+    sprite_position - 1 <= pixel_position && sprite_position + 1 >= pixel_position
+}
+
+/// Lit pixels of the CRT, the pixels the program doesn't reach stay dark.
+pub fn draw_picture(program: &[Instruction], width: usize, height: usize) -> Grid<bool>
+{
+    let mut picture = Grid::new(height, width, false);
+    for state in Cpu::new(program).take(width * height)
+    {
+        let pixel = state.cycle - 1;
+        picture[(pixel / width, pixel % width)] = determine_symbol(state.x, state.cycle, width);
+    }
+    picture
+}
+
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+/// What part 2 answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtOutput
+{
+    /// The letters drawn on the screen.
+    Letters,
+    /// The screen itself, `#` for lit pixels.
+    Picture,
+}
+
+pub struct Day10
+{
+    pub output: CrtOutput,
+}
+
+impl Default for Day10
+{
+    fn default() -> Self
+    {
+        Day10 { output: CrtOutput::Letters }
+    }
+}
+
+impl Day10
+{
+    /// The example draws stripes rather than letters.
+    pub fn example() -> Self
+    {
+        Day10 { output: CrtOutput::Picture }
+    }
+}
+
+impl Solution for Day10
+{
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        cpu::parse_program(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        let cycle_numbers = HashSet::from([20, 60, 100, 140, 180, 220]);
+        calculate_sum_of_signal_strengths(input, &cycle_numbers).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        let picture = draw_picture(input, WIDTH, HEIGHT);
+        let rendered = picture.render(|&lit| if lit { '#' } else { '.' });
+        match self.output
+        {
+            CrtOutput::Picture => Answer::Grid(rendered),
+            CrtOutput::Letters => match ocr::decode(&picture)
+            {
+                Ok(letters) => letters.into(),
+                // Still show the screen so that it can be read by eye.
+                Err(unknown_glyph) => Answer::Grid(format!("{}\n\n{}", unknown_glyph, rendered)),
+            },
+        }
+    }
+}
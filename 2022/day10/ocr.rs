@@ -0,0 +1,81 @@
+//! Reads the capital letters the CRT draws in the 4x6 Advent of Code font.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::common::grid::Grid;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by a single empty column.
+const GLYPH_STEP: usize = GLYPH_WIDTH + 1;
+
+/// Letters of the font, rows of each bitmap are concatenated.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// A glyph of the picture that is not in the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph
+{
+    /// 0-based position of the glyph from the left.
+    pub index: usize,
+    /// `#`/`.` rows separated by `\n`.
+    pub bitmap: String,
+}
+
+impl fmt::Display for UnknownGlyph
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "glyph {} is not a known letter:\n{}", self.index, self.bitmap)
+    }
+}
+
+impl Error for UnknownGlyph {}
+
+fn glyph_bitmap(picture: &Grid<bool>, index: usize) -> String
+{
+    (0..GLYPH_HEIGHT).map(|row|
+    {
+        (0..GLYPH_WIDTH)
+            .map(|column| if picture.get((row, index * GLYPH_STEP + column)).copied().unwrap_or(false) { '#' } else { '.' })
+            .collect::<String>()
+    }).collect::<Vec<_>>().join("\n")
+}
+
+/// Decodes every glyph of the picture from left to right.
+pub fn decode(picture: &Grid<bool>) -> Result<String, UnknownGlyph>
+{
+    let glyphs = picture.columns().div_ceil(GLYPH_STEP);
+    (0..glyphs).map(|index|
+    {
+        let bitmap = glyph_bitmap(picture, index);
+        let key = bitmap.replace('\n', "");
+        FONT.iter().find(|(_, glyph)| *glyph == key).map(|(letter, _)| *letter).ok_or(UnknownGlyph { index, bitmap })
+    }).collect()
+}
+
+/// Renders lit pixels as full blocks, which is easier to read than `#` and `.`.
+pub fn pretty(picture: &Grid<bool>) -> String
+{
+    picture.render(|&lit| if lit { '█' } else { ' ' })
+}
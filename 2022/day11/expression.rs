@@ -0,0 +1,198 @@
+//! Arithmetic of the monkeys' `Operation: new = ...` lines.
+
+use std::fmt;
+
+use peg;
+
+use super::worry::Worry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator
+{
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Operator
+{
+    fn precedence(self) -> u8
+    {
+        match self
+        {
+            Operator::Add | Operator::Subtract => 1,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 2,
+        }
+    }
+
+    fn symbol(self) -> char
+    {
+        match self
+        {
+            Operator::Add => '+',
+            Operator::Subtract => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+            Operator::Remainder => '%',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationError
+{
+    /// The result doesn't fit the worry type.
+    Overflow,
+    DivisionByZero,
+}
+
+impl fmt::Display for EvaluationError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            EvaluationError::Overflow => write!(f, "overflow"),
+            EvaluationError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression
+{
+    /// The worry level before the inspection.
+    Old,
+    Literal(i64),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression
+{
+    fn binary(left: Expression, operator: Operator, right: Expression) -> Self
+    {
+        Expression::Binary(Box::new(left), operator, Box::new(right))
+    }
+
+    /// Parses the right-hand side of `new = ...`.
+    pub fn parse(text: &str) -> Result<Self, peg::error::ParseError<peg::str::LineCol>>
+    {
+        expression_parser::expression(text)
+    }
+
+    /// Parses a whole `new = ...` operation.
+    pub fn parse_operation(text: &str) -> Result<Self, peg::error::ParseError<peg::str::LineCol>>
+    {
+        expression_parser::operation(text)
+    }
+
+    /// Whether the result modulo any number only depends on `old` modulo that number, which holds when the
+    /// expression only adds, subtracts and multiplies.
+    pub fn preserves_remainders(&self) -> bool
+    {
+        match self
+        {
+            Expression::Old | Expression::Literal(_) => true,
+            Expression::Binary(left, operator, right) =>
+                matches!(operator, Operator::Add | Operator::Subtract | Operator::Multiply)
+                    && left.preserves_remainders() && right.preserves_remainders(),
+        }
+    }
+
+    pub fn evaluate<W: Worry>(&self, old: &W) -> Result<W, EvaluationError>
+    {
+        match self
+        {
+            Expression::Old => Ok(old.clone()),
+            Expression::Literal(value) => Ok(W::from_i64(*value)),
+            Expression::Binary(left, operator, right) =>
+            {
+                let (left, right) = (left.evaluate(old)?, right.evaluate(old)?);
+                let result = match operator
+                {
+                    Operator::Add => left.checked_add(&right),
+                    Operator::Subtract => left.checked_sub(&right),
+                    Operator::Multiply => left.checked_mul(&right),
+                    Operator::Divide | Operator::Remainder if right.is_zero() => return Err(EvaluationError::DivisionByZero),
+                    Operator::Divide => left.checked_div(&right),
+                    Operator::Remainder => left.checked_rem(&right),
+                };
+                result.ok_or(EvaluationError::Overflow)
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8
+    {
+        match self
+        {
+            Expression::Binary(_, operator, _) => operator.precedence(),
+            _ => u8::MAX,
+        }
+    }
+}
+
+impl fmt::Display for Expression
+{
+    /// Prints the expression in the input syntax, with parentheses only where they are needed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Expression::Old => write!(f, "old"),
+            Expression::Literal(value) => write!(f, "{}", value),
+            Expression::Binary(left, operator, right) =>
+            {
+                let precedence = operator.precedence();
+                if left.precedence() < precedence
+                {
+                    write!(f, "({})", left)?;
+                }
+                else
+                {
+                    write!(f, "{}", left)?;
+                }
+                write!(f, " {} ", operator.symbol())?;
+                // Operators are left-associative, so an equal precedence on the right needs parentheses.
+                if right.precedence() <= precedence
+                {
+                    write!(f, "({})", right)
+                }
+                else
+                {
+                    write!(f, "{}", right)
+                }
+            }
+        }
+    }
+}
+
+peg::parser!{
+    grammar expression_parser() for str {
+        rule _ = quiet!{[' ' | '\t']*}
+
+        rule literal() -> Expression
+          = n:$(['0'..='9']+) {? n.parse().map(Expression::Literal).or(Err("integer literal")) }
+
+        rule term() -> Expression = precedence!{
+            x:(@) _ "+" _ y:@ { Expression::binary(x, Operator::Add, y) }
+            x:(@) _ "-" _ y:@ { Expression::binary(x, Operator::Subtract, y) }
+            --
+            x:(@) _ "*" _ y:@ { Expression::binary(x, Operator::Multiply, y) }
+            x:(@) _ "/" _ y:@ { Expression::binary(x, Operator::Divide, y) }
+            x:(@) _ "%" _ y:@ { Expression::binary(x, Operator::Remainder, y) }
+            --
+            "old" { Expression::Old }
+            l:literal() { l }
+            "(" _ e:term() _ ")" { e }
+        }
+
+        pub rule expression() -> Expression
+          = _ e:term() _ { e }
+
+        pub rule operation() -> Expression
+          = _ "new" _ "=" e:expression() { e }
+    }
+}
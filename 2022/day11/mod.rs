@@ -0,0 +1,503 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::vec;
+
+pub mod expression;
+pub mod worry;
+
+use crate::error::{numbered_blocks, ParseError};
+use crate::solution::{Answer, Solution};
+use expression::{EvaluationError, Expression};
+use num_bigint::BigInt;
+use worry::{Worry, WorryType};
+
+const MONKEY_PATTERN: &str = 
+r"\s*?Monkey (?<number>\d+):
+(\s)*?Starting items: (?<starting_items>[,\s\d]+)
+(\s)*?Operation: (?<operation>.*)
+(\s)*?Test: divisible by (?<denominator>\d+)
+(\s)*?If true: throw to monkey (?<true_option>\d+)
+(\s)*?If false: throw to monkey (?<false_option>\d+)";
+
+type WorryLevel = i64;
+
+fn apply_relief<W: Worry>(x: &W) -> W
+{
+    x.checked_div(&W::from_i64(3)).expect("division by three doesn't overflow")
+}
+
+fn apply_relief_by_chineese_theorem_<W: Worry>(product: &W, x: &W) -> W
+{
+    x.checked_rem(product).expect("the product of positive divisors is positive")
+}
+
+#[derive(Debug, Clone)]
+struct Test
+{
+    number: WorryLevel,
+    true_option: usize,
+    false_option: usize,
+}
+
+impl Test
+{
+    fn test<W: Worry>(&self, input: &W) -> usize {
+        let remainder = input.checked_rem(&W::from_i64(self.number)).expect("divisors are positive");
+        if remainder.is_zero()
+        {
+            self.true_option
+        }
+        else
+        {
+            self.false_option
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey
+{
+
+    number: usize,
+    items: Vec<WorryLevel>,
+    operation: Expression,
+    test: Test
+}
+
+/// Identifies an item by where it started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemId
+{
+    /// Number of the monkey holding the item at the start.
+    pub monkey: usize,
+    /// Position of the item in that monkey's starting items.
+    pub index: usize,
+}
+
+impl fmt::Display for ItemId
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "starting item {} of monkey {}", self.index, self.monkey)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Item<W>
+{
+    id: ItemId,
+    worry_level: W,
+}
+
+/// An inspection that the worry type can't carry out.
+#[derive(Debug, Clone)]
+pub struct InspectionError<W>
+{
+    /// 1-based number of the round.
+    pub round: usize,
+    /// Number of the inspecting monkey.
+    pub monkey: usize,
+    pub item: ItemId,
+    /// Worry level of the item before the inspection.
+    pub worry_level: W,
+    pub operation: Expression,
+    pub error: EvaluationError,
+}
+
+impl<W: Worry> fmt::Display for InspectionError<W>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "round {}, monkey {} inspecting {} (worry level {}): {} in `new = {}`",
+            self.round, self.monkey, self.item, self.worry_level, self.error, self.operation)
+    }
+}
+
+impl<W: Worry> std::error::Error for InspectionError<W> {}
+
+/// How the worry levels are kept manageable after each inspection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief
+{
+    /// Part 1: the worry level is divided by three.
+    DivideByThree,
+    /// Part 2: the worry level is reduced modulo the product of the divisors, which keeps every test result as long
+    /// as the operations only add, subtract and multiply; otherwise it is left as it is.
+    ModuloDivisors,
+}
+
+/// Reducing the worry levels modulo the product of the divisors only keeps the test results when no operation
+/// divides or takes a remainder.
+fn reducible(monkeys: &[Monkey]) -> bool
+{
+    monkeys.iter().all(|monkey| monkey.operation.preserves_remainders())
+}
+
+fn divisor_product<W: Worry>(monkeys: &[Monkey]) -> Option<W>
+{
+    monkeys.iter().try_fold(W::from_i64(1), |product, monkey| product.checked_mul(&W::from_i64(monkey.test.number)))
+}
+
+#[derive(Debug)]
+struct State<W>
+{
+    items: Vec<Vec<Item<W>>>,
+    inspected_items_by_monkeys: Vec<usize>
+}
+
+/// Points at the first line of `monkey_lines` that doesn't match its line of [`MONKEY_PATTERN`].
+fn locate_mismatch(first_line: usize, monkey_lines: &str) -> ParseError
+{
+    let lines: Vec<&str> = monkey_lines.lines().collect();
+    for (index, pattern_line) in MONKEY_PATTERN.lines().enumerate()
+    {
+        let line = lines.get(index).copied().unwrap_or_default();
+        let line_pattern = Regex::new(&format!("^{}$", pattern_line)).unwrap();
+        if !line_pattern.is_match(line)
+        {
+            let expected = pattern_line.split(':').next().unwrap_or_default().trim_start_matches(r"\s*?").trim_start_matches(r"(\s)*?");
+            let column = line.len() - line.trim_start().len() + 1;
+            return ParseError::new(first_line + index, column, line, format!("expected `{}: ...`", expected));
+        }
+    }
+    let index = MONKEY_PATTERN.lines().count();
+    ParseError::new(first_line + index, 1, lines.get(index).copied().unwrap_or_default(), "unexpected line after the monkey description")
+}
+
+fn read_input(contents: &str) -> Result<Vec<Monkey>, ParseError>
+{
+    let monkey_pattern = Regex::new(&format!("^{}$", MONKEY_PATTERN)).unwrap();
+    let mut data: Vec<Monkey> = Vec::new();
+    // Throw targets can only be checked once every monkey is known, each with the error pointing at it.
+    let mut targets: Vec<(usize, ParseError)> = Vec::new();
+    let mut first_header = None;
+    for (first_line, monkey_lines) in numbered_blocks(contents)
+    {
+        let monkey_input = monkey_pattern.captures(monkey_lines).ok_or_else(|| locate_mismatch(first_line, monkey_lines))?;
+        // Points at a capture group of the block in terms of its own line.
+        let error_at = |name: &str, message: &str|
+        {
+            let group = monkey_input.name(name).unwrap();
+            let line_start = monkey_lines[..group.start()].rfind('\n').map_or(0, |position| position + 1);
+            let line_end = monkey_lines[group.start()..].find('\n').map_or(monkey_lines.len(), |position| group.start() + position);
+            let line = &monkey_lines[line_start..line_end];
+            let line_number = first_line + monkey_lines[..line_start].matches('\n').count();
+            ParseError::new(line_number, monkey_lines[line_start..group.start()].chars().count() + 1, line, message)
+        };
+        let parse_index = |name: &str| monkey_input[name].parse::<usize>().map_err(|_| error_at(name, "expected a monkey number"));
+
+        let number = parse_index("number")?;
+        if data.is_empty()
+        {
+            first_header = Some(error_at("number", ""));
+        }
+        if number != data.len()
+        {
+            return Err(error_at("number", &format!("expected monkey {}, the monkeys are numbered in order", data.len())));
+        }
+        let items = monkey_input["starting_items"].split(',')
+            .map(|x| x.trim().parse::<WorryLevel>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| error_at("starting_items", "expected a comma-separated list of worry levels"))?;
+        let operation = Expression::parse_operation(&monkey_input["operation"]).map_err(|error|
+        {
+            let mut parse_error = error_at("operation", &format!("expected {}", error.expected));
+            parse_error.column += error.location.column - 1;
+            parse_error
+        })?;
+        let denominator = monkey_input["denominator"].parse::<WorryLevel>().ok().filter(|&divisor| divisor != 0)
+            .ok_or_else(|| error_at("denominator", "expected a positive divisor"))?;
+        let (true_option, false_option) = (parse_index("true_option")?, parse_index("false_option")?);
+        targets.push((true_option, error_at("true_option", "")));
+        targets.push((false_option, error_at("false_option", "")));
+
+        data.push(Monkey {
+            number,
+            items,
+            operation,
+            test: Test{number: denominator, true_option, false_option}
+        });
+    }
+    if data.len() < 2
+    {
+        let mut error = first_header.unwrap_or_else(|| ParseError::new(1, 1, "", ""));
+        error.message = "expected at least two monkeys, the monkey business multiplies the two highest counts".to_string();
+        return Err(error);
+    }
+    if let Some((target, mut error)) = targets.into_iter().find(|(target, _)| *target >= data.len())
+    {
+        error.message = format!("there is no monkey {}, the last one is monkey {}", target, data.len() - 1);
+        return Err(error);
+    }
+    Ok(data)
+}
+
+/// Worry level of `item` after `monkey` inspects it, before relief.
+fn inspect<W: Worry>(monkey: &Monkey, round: usize, item: &Item<W>) -> Result<W, InspectionError<W>>
+{
+    monkey.operation.evaluate(&item.worry_level).map_err(|error| InspectionError {
+        round,
+        monkey: monkey.number,
+        item: item.id,
+        worry_level: item.worry_level.clone(),
+        operation: monkey.operation.clone(),
+        error,
+    })
+}
+
+fn simulate<W: Worry>(monkeys: &[Monkey], state: &mut State<W>, rounds: usize, relief: impl Fn(W) -> W) -> Result<(), InspectionError<W>>
+{
+    for round in 1..=rounds
+    {
+        for (i, monkey) in monkeys.iter().enumerate()
+        {
+            let items = std::mem::take(&mut state.items[i]);
+            state.inspected_items_by_monkeys[i] += items.len();
+            for item in items
+            {
+                let worry_level = relief(inspect(monkey, round, &item)?);
+                let destination = monkey.test.test(&worry_level);
+                state.items[destination].push(Item { id: item.id, worry_level });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Moves a single item through one round, counting its inspections, and returns the monkey holding it at the end.
+///
+/// Monkeys take their turns in order, so the item is inspected again in the same round when it is thrown forward.
+fn advance_item<W: Worry>(monkeys: &[Monkey], round: usize, mut holder: usize, item: &mut Item<W>, relief: &impl Fn(W) -> W, inspections: &mut [u64]) -> Result<usize, InspectionError<W>>
+{
+    loop
+    {
+        let monkey = &monkeys[holder];
+        item.worry_level = relief(inspect(monkey, round, item)?);
+        inspections[holder] += 1;
+        let destination = monkey.test.test(&item.worry_level);
+        if destination <= holder
+        {
+            return Ok(destination);
+        }
+        holder = destination;
+    }
+}
+
+/// Inspections of a single item by every monkey over `rounds` rounds.
+///
+/// The item's trajectory doesn't depend on the other items, so once its (monkey, worry level) state at the start
+/// of a round repeats, the remaining rounds are extrapolated from the cycle.
+fn item_inspections<W: Worry>(monkeys: &[Monkey], holder: usize, mut item: Item<W>, rounds: u64, relief: &impl Fn(W) -> W) -> Result<Vec<u64>, InspectionError<W>>
+{
+    let mut holder = holder;
+    let mut first_seen = HashMap::from([((holder, item.worry_level.clone()), 0)]);
+    // Inspections by monkey after each number of rounds.
+    let mut history = vec![vec![0; monkeys.len()]];
+    while (history.len() as u64) <= rounds
+    {
+        let round = history.len();
+        let mut inspections = history[round - 1].clone();
+        holder = advance_item(monkeys, round, holder, &mut item, relief, &mut inspections)?;
+        history.push(inspections);
+        if let Some(&start) = first_seen.get(&(holder, item.worry_level.clone()))
+        {
+            let period = (round - start) as u64;
+            let remaining = rounds - round as u64;
+            let rest = start + (remaining % period) as usize;
+            return Ok((0..monkeys.len())
+                .map(|i| history[round][i] + remaining / period * (history[round][i] - history[start][i]) + history[rest][i] - history[start][i])
+                .collect());
+        }
+        first_seen.insert((holder, item.worry_level.clone()), round);
+    }
+    Ok(history.pop().unwrap())
+}
+
+/// Why [`monkey_business_by_cycles`] can't count the inspections.
+#[derive(Debug, Clone)]
+pub enum CycleError<W>
+{
+    /// The product of the divisors doesn't fit into the worry type, so the worry levels are never reduced and
+    /// the trajectories can't be expected to repeat.
+    DivisorProductOverflow,
+    /// The operation of this monkey divides or takes a remainder, so reducing the worry levels would change the
+    /// trajectories.
+    IrreducibleOperation(usize),
+    Inspection(InspectionError<W>),
+}
+
+impl<W> From<InspectionError<W>> for CycleError<W>
+{
+    fn from(error: InspectionError<W>) -> Self
+    {
+        CycleError::Inspection(error)
+    }
+}
+
+impl<W: Worry> fmt::Display for CycleError<W>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            CycleError::DivisorProductOverflow => write!(f, "the product of the divisors overflows, so the items can't be followed through cycles"),
+            CycleError::IrreducibleOperation(monkey) =>
+                write!(f, "the operation of monkey {} divides or takes a remainder, so the items can't be followed through cycles", monkey),
+            CycleError::Inspection(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<W: Worry> std::error::Error for CycleError<W> {}
+
+/// Same as [`monkey_business`] with [`Relief::ModuloDivisors`], but follows every item on its own and extrapolates
+/// the inspections from the cycles of its trajectory, so `rounds` can be arbitrarily large.
+pub fn monkey_business_by_cycles<W: Worry>(monkeys: &[Monkey], rounds: u64) -> Result<u128, CycleError<W>>
+{
+    if let Some(monkey) = monkeys.iter().find(|monkey| !monkey.operation.preserves_remainders())
+    {
+        return Err(CycleError::IrreducibleOperation(monkey.number));
+    }
+    let product = divisor_product::<W>(monkeys).ok_or(CycleError::DivisorProductOverflow)?;
+    let relief = |x: W| apply_relief_by_chineese_theorem_(&product, &x);
+    let mut inspected_items_by_monkeys = vec![0; monkeys.len()];
+    for (holder, items) in new_state::<W>(monkeys).items.into_iter().enumerate()
+    {
+        for item in items
+        {
+            let inspections = item_inspections(monkeys, holder, item, rounds, &relief)?;
+            inspected_items_by_monkeys.iter_mut().zip(inspections).for_each(|(total, count)| *total += count);
+        }
+    }
+    inspected_items_by_monkeys.sort();
+    Ok(inspected_items_by_monkeys.iter().rev().take(2).map(|&count| count as u128).product())
+}
+
+fn calculate_monkey_business<W>(state: &State<W>) -> usize
+{
+    let mut inspected_items_sorted = state.inspected_items_by_monkeys.clone();
+    inspected_items_sorted.sort();
+    inspected_items_sorted[inspected_items_sorted.len() - 1] * inspected_items_sorted[inspected_items_sorted.len() - 2]
+}
+
+fn new_state<W: Worry>(monkeys: &[Monkey]) -> State<W>
+{
+    let items = monkeys.iter()
+        .map(|monkey| monkey.items.iter().enumerate()
+            .map(|(index, &worry_level)| Item { id: ItemId { monkey: monkey.number, index }, worry_level: W::from_i64(worry_level) })
+            .collect())
+        .collect();
+    State{items, inspected_items_by_monkeys: vec![0; monkeys.len()]}
+}
+
+/// Product of the two largest inspection counts after `rounds` rounds, computed in `W` with checked arithmetic.
+pub fn monkey_business<W: Worry>(monkeys: &[Monkey], rounds: usize, relief: Relief) -> Result<usize, InspectionError<W>>
+{
+    let mut state = new_state::<W>(monkeys);
+    match relief
+    {
+        Relief::DivideByThree => simulate(monkeys, &mut state, rounds, |x| apply_relief(&x))?,
+        // Without a representable product or with `/` and `%` in the operations nothing is reduced, so the
+        // simulation stays exact and an overflow is still reported where it happens.
+        Relief::ModuloDivisors => match divisor_product::<W>(monkeys).filter(|_| reducible(monkeys))
+        {
+            Some(product) => simulate(monkeys, &mut state, rounds, |x| apply_relief_by_chineese_theorem_(&product, &x))?,
+            None => simulate(monkeys, &mut state, rounds, |x| x)?,
+        },
+    }
+    Ok(calculate_monkey_business(&state))
+}
+
+/// How part 2 goes through the rounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Simulation
+{
+    /// All monkeys take all their turns, see [`monkey_business`].
+    Literal,
+    /// Every item is followed until its trajectory repeats, see [`monkey_business_by_cycles`].
+    Cycles,
+}
+
+pub struct Day11
+{
+    /// Number type the worry levels are kept in.
+    pub worry: WorryType,
+    pub simulation: Simulation,
+}
+
+impl Default for Day11
+{
+    fn default() -> Self
+    {
+        Day11 { worry: WorryType::I64, simulation: Simulation::Literal }
+    }
+}
+
+impl Day11
+{
+    pub fn with_worry(self, worry: WorryType) -> Self
+    {
+        Day11 { worry, ..self }
+    }
+
+    pub fn with_simulation(self, simulation: Simulation) -> Self
+    {
+        Day11 { simulation, ..self }
+    }
+
+    /// [`monkey_business`] in the selected worry type, the error is the description of the failed inspection.
+    pub fn try_monkey_business(&self, monkeys: &[Monkey], rounds: usize, relief: Relief) -> Result<usize, String>
+    {
+        match self.worry
+        {
+            WorryType::I64 => monkey_business::<i64>(monkeys, rounds, relief).map_err(|error| error.to_string()),
+            WorryType::I128 => monkey_business::<i128>(monkeys, rounds, relief).map_err(|error| error.to_string()),
+            WorryType::BigInt => monkey_business::<BigInt>(monkeys, rounds, relief).map_err(|error| error.to_string()),
+        }
+    }
+
+    /// [`monkey_business_by_cycles`] in the selected worry type.
+    pub fn try_monkey_business_by_cycles(&self, monkeys: &[Monkey], rounds: u64) -> Result<u128, String>
+    {
+        match self.worry
+        {
+            WorryType::I64 => monkey_business_by_cycles::<i64>(monkeys, rounds).map_err(|error| error.to_string()),
+            WorryType::I128 => monkey_business_by_cycles::<i128>(monkeys, rounds).map_err(|error| error.to_string()),
+            WorryType::BigInt => monkey_business_by_cycles::<BigInt>(monkeys, rounds).map_err(|error| error.to_string()),
+        }
+    }
+}
+
+impl Solution for Day11
+{
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        match self.try_monkey_business(input, 20, Relief::DivideByThree)
+        {
+            Ok(business) => business.into(),
+            Err(error) => Answer::Error(error),
+        }
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        let business = match self.simulation
+        {
+            Simulation::Literal => self.try_monkey_business(input, 10000, Relief::ModuloDivisors).map(|business| business as u128),
+            Simulation::Cycles => self.try_monkey_business_by_cycles(input, 10000),
+        };
+        match business.and_then(|business| i64::try_from(business).map_err(|_| format!("the monkey business {} doesn't fit into 64 bits", business)))
+        {
+            Ok(business) => business.into(),
+            Err(error) => Answer::Error(error),
+        }
+    }
+}
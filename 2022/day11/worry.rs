@@ -0,0 +1,143 @@
+//! Number types the worry levels can be kept in.
+
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+/// Worry level arithmetic that reports overflow instead of wrapping.
+pub trait Worry: Clone + Eq + Hash + fmt::Debug + fmt::Display + 'static
+{
+    fn from_i64(value: i64) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// `None` on division by zero as well as on overflow.
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    /// `None` on division by zero as well as on overflow.
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_worry_for_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Worry for $t
+            {
+                fn from_i64(value: i64) -> Self
+                {
+                    value.into()
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self>
+                {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self>
+                {
+                    <$t>::checked_sub(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self>
+                {
+                    <$t>::checked_mul(*self, *other)
+                }
+
+                fn checked_div(&self, other: &Self) -> Option<Self>
+                {
+                    <$t>::checked_div(*self, *other)
+                }
+
+                fn checked_rem(&self, other: &Self) -> Option<Self>
+                {
+                    <$t>::checked_rem(*self, *other)
+                }
+
+                fn is_zero(&self) -> bool
+                {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_worry_for_primitive!(i64, i128);
+
+impl Worry for BigInt
+{
+    fn from_i64(value: i64) -> Self
+    {
+        value.into()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self>
+    {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>
+    {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>
+    {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self>
+    {
+        (!other.is_zero()).then(|| self / other)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self>
+    {
+        (!other.is_zero()).then(|| self % other)
+    }
+
+    fn is_zero(&self) -> bool
+    {
+        *self == BigInt::ZERO
+    }
+}
+
+/// Which [`Worry`] type the simulation runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorryType
+{
+    I64,
+    I128,
+    BigInt,
+}
+
+impl FromStr for WorryType
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        match s
+        {
+            "i64" => Ok(WorryType::I64),
+            "i128" => Ok(WorryType::I128),
+            "bigint" => Ok(WorryType::BigInt),
+            _ => Err(format!("expected i64, i128 or bigint, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for WorryType
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            WorryType::I64 => write!(f, "i64"),
+            WorryType::I128 => write!(f, "i128"),
+            WorryType::BigInt => write!(f, "bigint"),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::common::grid::{Grid, Position};
+use crate::common::search;
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Clone, Copy)]
+pub enum Cell
+{
+    Start,
+    End,
+    Free,
+}
+
+pub type Height = i64;
+pub type HeightMap = Grid<(Cell, Height)>;
+
+fn read_input(contents: &str) -> Result<HeightMap, ParseError>
+{
+    let height_map = Grid::parse(contents, "a height from `a` to `z`, `S` or `E`", |cell|
+        {
+            match cell
+            {
+                'S' => Some((Cell::Start, 0)),
+                'E' => Some((Cell::End, ('z' as i64) - ('a' as i64))),
+                'a'..='z' => Some((Cell::Free, (cell as i64) - ('a' as i64))),
+                _ => None,
+            }
+        })?;
+    for (cell, name) in [(Cell::Start, "S"), (Cell::End, "E")]
+    {
+        let count = height_map.iter().filter(|(_, (other, _))| *other == cell).count();
+        if count != 1
+        {
+            return Err(ParseError::new(1, 1, contents.lines().next().unwrap_or_default(),
+                format!("expected exactly one `{}` on the map, found {}", name, count)));
+        }
+    }
+    Ok(height_map)
+}
+
+/// Positions one step away that are at most one unit higher.
+fn climbable_neighbours(height_map: &HeightMap, position: Position) -> impl Iterator<Item = Position> + '_
+{
+    height_map.neighbours_4(position).filter(move |neighbour| height_map[*neighbour].1 - height_map[position].1 <= 1)
+}
+
+fn find_all(height_map: &HeightMap, accept: impl Fn(&(Cell, Height)) -> bool) -> Vec<Position>
+{
+    height_map.iter().filter(|(_, cell)| accept(cell)).map(|(position, _)| position).collect()
+}
+
+fn find_single(height_map: &HeightMap, cell: Cell) -> Position
+{
+    height_map.find(|&(other, _)| other == cell).expect("the map has been checked to have S and E")
+}
+
+fn manhattan_distance(first: Position, second: Position) -> usize
+{
+    first.0.abs_diff(second.0) + first.1.abs_diff(second.1)
+}
+
+/// Shortest route from S to E, both included.
+pub fn route_from_start(height_map: &HeightMap) -> Option<Vec<Position>>
+{
+    let (start, end) = (find_single(height_map, Cell::Start), find_single(height_map, Cell::End));
+    let result = search::a_star([start], &end,
+        |&position| climbable_neighbours(height_map, position).map(|neighbour| (neighbour, 1)),
+        |&position| manhattan_distance(position, end));
+    result.path_to(&end)
+}
+
+/// Shortest route to E from any of the lowest cells, the first position is the chosen one.
+pub fn route_from_lowest(height_map: &HeightMap) -> Option<Vec<Position>>
+{
+    let lowest = find_all(height_map, |&(_, height)| height == 0);
+    let result = search::bfs(lowest, |&position| climbable_neighbours(height_map, position));
+    result.path_to(&find_single(height_map, Cell::End))
+}
+
+/// How [`render_route`] marks the route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteStyle
+{
+    /// The puzzle's notation: arrows along the route, `.` elsewhere, and `S`, `E` and `a` at the landmarks.
+    Plain,
+    /// The whole height map, dimmed, with the route and the landmarks in colour.
+    Ansi,
+}
+
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+const DIM: &str = "\x1b[2m";
+const START: &str = "\x1b[1;30;42m";
+const END: &str = "\x1b[1;30;41m";
+const CHOSEN: &str = "\x1b[1;30;46m";
+const RESET: &str = "\x1b[0m";
+
+fn arrow(from: Position, to: Position) -> char
+{
+    match (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize)
+    {
+        (-1, 0) => '^',
+        (1, 0) => 'v',
+        (0, -1) => '<',
+        (0, 1) => '>',
+        _ => panic!("{:?} and {:?} are not neighbours", from, to),
+    }
+}
+
+/// Draws `route` onto the map; its first position is highlighted as the chosen start unless it is S.
+pub fn render_route(height_map: &HeightMap, route: &[Position], style: RouteStyle) -> String
+{
+    let mut arrows = Grid::new(height_map.rows(), height_map.columns(), None);
+    for step in route.windows(2)
+    {
+        arrows[step[0]] = Some(arrow(step[0], step[1]));
+    }
+    let chosen = route.first().copied();
+    let mut rendered = String::new();
+    for row in 0..height_map.rows()
+    {
+        for column in 0..height_map.columns()
+        {
+            let position = (row, column);
+            let (cell, height) = height_map[position];
+            let letter = match cell
+            {
+                Cell::Start => 'S',
+                Cell::End => 'E',
+                Cell::Free => (b'a' + height as u8) as char,
+            };
+            match style
+            {
+                RouteStyle::Plain => rendered.push(match cell
+                {
+                    Cell::Start | Cell::End => letter,
+                    _ if chosen == Some(position) => letter,
+                    _ => arrows[position].unwrap_or('.'),
+                }),
+                RouteStyle::Ansi =>
+                {
+                    let (colour, symbol) = match cell
+                    {
+                        Cell::Start => (START, letter),
+                        Cell::End => (END, letter),
+                        _ if chosen == Some(position) => (CHOSEN, letter),
+                        _ => match arrows[position]
+                        {
+                            Some(arrow) => (BOLD_YELLOW, arrow),
+                            None => (DIM, letter),
+                        },
+                    };
+                    rendered.push_str(&format!("{}{}{}", colour, symbol, RESET));
+                },
+            }
+        }
+        rendered.push('\n');
+    }
+    rendered.pop();
+    rendered
+}
+
+fn steps(route: Option<Vec<Position>>) -> Answer
+{
+    match route
+    {
+        Some(route) => (route.len() - 1).into(),
+        None => Answer::Error("E can't be reached".to_string()),
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12
+{
+    type Input = HeightMap;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        steps(route_from_start(input))
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        steps(route_from_lowest(input))
+    }
+}
@@ -0,0 +1,302 @@
+use core::cmp::Ordering;
+use std::fmt;
+use itertools::Itertools;
+use peg;
+
+use crate::error::{numbered_blocks, numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+#[derive(Eq)]
+#[derive(Clone)]
+pub enum Value
+{
+    List(Vec<Value>),
+    Digit(i64),
+}
+
+type Packet = Value;
+type PacketPair = Vec<Packet>;
+type Data = Vec<PacketPair>;
+
+peg::parser!{
+    grammar list_parser() for str {
+        rule _ = quiet!{[' ' | '\t' | '\r' | '\n']*}
+
+        rule number() -> Value
+          = n:$(['0'..='9']+) {? n.parse().map_or(Err("i64"), |x| Ok(Value::Digit(x))) }
+
+        rule list() -> Value
+          = "[" _ l:((number()/list()) ** (_ "," _)) _ "]" { Value::List(l) }
+
+        pub rule packet() -> Value
+          = _ l:list() _ { l }
+    }
+}
+
+impl Value
+{
+    /// Parses a packet, whitespace between the tokens is allowed so that pretty-printed packets read back.
+    pub fn parse(text: &str) -> Result<Self, peg::error::ParseError<peg::str::LineCol>>
+    {
+        list_parser::packet(text)
+    }
+
+    /// Same as `{:#}`: lists holding other lists are spread over indented lines.
+    pub fn pretty(&self) -> String
+    {
+        format!("{:#}", self)
+    }
+
+    fn write_pretty(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result
+    {
+        match self
+        {
+            Value::List(values) if values.iter().any(|value| matches!(value, Value::List(_))) =>
+            {
+                writeln!(f, "[")?;
+                for (index, value) in values.iter().enumerate()
+                {
+                    write!(f, "{:width$}", "", width = indent + 2)?;
+                    value.write_pretty(f, indent + 2)?;
+                    writeln!(f, "{}", if index + 1 < values.len() { "," } else { "" })?;
+                }
+                write!(f, "{:width$}]", "", width = indent)
+            }
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
+impl fmt::Display for Value
+{
+    /// Prints the packet the way the input writes it, `{:#}` pretty-prints it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if f.alternate()
+        {
+            return self.write_pretty(f, 0);
+        }
+        match self
+        {
+            Value::Digit(digit) => write!(f, "{}", digit),
+            Value::List(values) => write!(f, "[{}]", values.iter().join(",")),
+        }
+    }
+}
+
+impl From<&Value> for serde_json::Value
+{
+    fn from(value: &Value) -> Self
+    {
+        match value
+        {
+            Value::Digit(digit) => (*digit).into(),
+            Value::List(values) => serde_json::Value::Array(values.iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl TryFrom<&serde_json::Value> for Value
+{
+    type Error = String;
+
+    /// Only arrays and non-negative integers are packet values.
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error>
+    {
+        match value
+        {
+            serde_json::Value::Array(values) => values.iter().map(Value::try_from).collect::<Result<_, _>>().map(Value::List),
+            serde_json::Value::Number(number) => number.as_u64()
+                .and_then(|digit| i64::try_from(digit).ok())
+                .map(Value::Digit)
+                .ok_or_else(|| format!("expected a non-negative integer, found {}", number)),
+            other => Err(format!("expected an array or an integer, found {}", other)),
+        }
+    }
+}
+
+fn read_input(contents: &str) -> Result<Data, ParseError>
+{
+    numbered_blocks(contents).map(|(first_line, line_pair)|
+    {
+        let pair: Vec<Value> = numbered_lines(line_pair).map(|(index, line)|
+            {
+                let number = first_line + index - 1;
+                Value::parse(line).map_err(|error| ParseError::from_peg(number, line, error))
+            }).collect::<Result<_, _>>()?;
+        if pair.len() != 2
+        {
+            return Err(ParseError::new(first_line, 1, line_pair.lines().next().unwrap_or_default(),
+                format!("expected a pair of packets, found {}", pair.len())));
+        }
+        Ok(pair)
+    }).collect()
+}
+
+impl PartialEq for Value
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Ord for Value
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        match (self, other)
+        {
+            (Value::Digit(digit1), Value::Digit(digit2)) => digit1.cmp(digit2),
+            (Value::Digit(_), Value::List(_)) => {
+                Value::List(vec![self.clone()]).cmp(other)
+            },
+            (Value::List(_), Value::Digit(_)) => {
+                self.cmp(&Value::List(vec![other.clone()]))
+            },
+            (Value::List(list1), Value::List(list2)) => {
+                list1.iter().zip(list2.iter()).fold(
+                Ordering::Equal, |acc, (elem1, elem2)| acc.then_with(|| elem1.cmp(elem2))
+                ).then(list1.len().cmp(&list2.len()))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Value
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+/// Steps of a packet comparison, see [`Value::compare_explained`].
+#[derive(Debug, Clone)]
+pub struct Explanation
+{
+    pub ordering: Ordering,
+    /// Nesting depth and text of every line.
+    lines: Vec<(usize, String)>,
+}
+
+impl fmt::Display for Explanation
+{
+    /// One `- ` line per step, nested steps indented by two more spaces.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let lines: Vec<String> = self.lines.iter().map(|(depth, text)| format!("{:width$}- {}", "", text, width = 2 * depth)).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl Value
+{
+    /// Compares like [`Ord`] and records every step the way the puzzle description walks through the example.
+    pub fn compare_explained(&self, other: &Value) -> Explanation
+    {
+        let mut lines = Vec::new();
+        let ordering = self.explain(other, 0, &mut lines);
+        if ordering == Ordering::Equal
+        {
+            lines.push((1, "Both packets are equal, so the order is not decided".to_string()));
+        }
+        Explanation { ordering, lines }
+    }
+
+    fn explain(&self, other: &Value, depth: usize, lines: &mut Vec<(usize, String)>) -> Ordering
+    {
+        lines.push((depth, format!("Compare {} vs {}", self, other)));
+        match (self, other)
+        {
+            (Value::Digit(digit1), Value::Digit(digit2)) =>
+            {
+                let ordering = digit1.cmp(digit2);
+                match ordering
+                {
+                    Ordering::Less => lines.push((depth + 1, "Left side is smaller, so inputs are in the right order".to_string())),
+                    Ordering::Greater => lines.push((depth + 1, "Right side is smaller, so inputs are not in the right order".to_string())),
+                    Ordering::Equal => {},
+                }
+                ordering
+            },
+            (Value::Digit(_), Value::List(_)) =>
+            {
+                lines.push((depth + 1, format!("Mixed types; convert left to [{}] and retry comparison", self)));
+                Value::List(vec![self.clone()]).explain(other, depth + 1, lines)
+            },
+            (Value::List(_), Value::Digit(_)) =>
+            {
+                lines.push((depth + 1, format!("Mixed types; convert right to [{}] and retry comparison", other)));
+                self.explain(&Value::List(vec![other.clone()]), depth + 1, lines)
+            },
+            (Value::List(list1), Value::List(list2)) =>
+            {
+                for (elem1, elem2) in list1.iter().zip(list2.iter())
+                {
+                    let ordering = elem1.explain(elem2, depth + 1, lines);
+                    if ordering != Ordering::Equal
+                    {
+                        return ordering;
+                    }
+                }
+                let ordering = list1.len().cmp(&list2.len());
+                match ordering
+                {
+                    Ordering::Less => lines.push((depth + 1, "Left side ran out of items, so inputs are in the right order".to_string())),
+                    Ordering::Greater => lines.push((depth + 1, "Right side ran out of items, so inputs are not in the right order".to_string())),
+                    Ordering::Equal => {},
+                }
+                ordering
+            }
+        }
+    }
+}
+
+fn calculate_sum_ordered_lists_indices(input: &Data) -> usize
+{
+    input.iter().enumerate().fold(0, |acc, (index, pair)| 
+        {
+            acc + (index + 1) * match pair[0].cmp(&pair[1])
+            {
+                Ordering::Less => 1,
+                _ => 0
+            }
+        }
+    )
+}
+
+fn find_decoder_key(input: &Data) -> usize
+{
+    let list_2 = Value::List(vec![Value::List(vec![Value::Digit(2)])]);
+    let list_6 = Value::List(vec![Value::List(vec![Value::Digit(6)])]);
+    input.iter().flatten().chain([list_2.clone(), list_6.clone()].iter()).sorted().enumerate().fold(1, |acc, (index, list)| 
+        {
+            acc * if list == &list_2 || list == &list_6 {index + 1} else {1}
+
+        }
+    )
+}
+
+pub struct Day13;
+
+impl Solution for Day13
+{
+    type Input = Data;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        calculate_sum_ordered_lists_indices(input).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        find_decoder_key(input).into()
+    }
+}
@@ -0,0 +1,617 @@
+use core::cmp::{max, min};
+use std::collections::HashSet;
+use std::str::FromStr;
+use peg;
+
+use crate::common::grid::SparseGrid;
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Ord, PartialOrd, Eq, PartialEq)]
+pub enum Value
+{
+    Rock,
+    Sand,
+    Water,
+}
+
+type Coord = i64;
+type X = Coord;
+type Y = Coord;
+type Point = (X, Y);
+type Line = Vec<Point>;
+type Data = Vec<Line>;
+
+type SparseMap = SparseGrid<Value>;
+
+peg::parser!{
+    grammar line_parser() for str {
+        rule coord() -> Coord
+          = n:$(['0'..='9']+) {? n.parse().or(Err("coordinate")) }
+        pub rule point() -> Point
+          = x:coord() "," y:coord() { (x,y) }
+        pub rule line() -> Line
+          = l:(point() ** " -> ") { l }
+    }
+}
+
+fn read_input(contents: &str) -> Result<Data, ParseError>
+{
+    numbered_lines(contents).map(|(number, line)|
+    {
+        line_parser::line(line).map_err(|error| ParseError::from_peg(number, line, error))
+    }).collect()
+}
+
+fn input_to_rock_map(data: &Data) -> SparseMap
+{
+    let mut rock_map = SparseMap::new();
+    let mut fill_map = |x_start, x_end, y_start, y_end| {
+        for x_i in x_start..=x_end
+        {
+            for y_i in y_start..=y_end
+            {
+                rock_map.insert((x_i, y_i), Value::Rock);
+            }
+        }
+    };
+
+    for line in data.iter()
+    {
+        for i in 0..line.len()-1
+        {
+            let (x1, y1) = line[i];
+            let (x2, y2) = line[i + 1];
+            let x_start = min(x1, x2);
+            let x_end = max(x1, x2);
+            let y_start = min(y1, y2);
+            let y_end = max(y1, y2);
+            if (x_start == x_end) || (y_start == y_end)
+            {
+                fill_map(x_start, x_end, y_start, y_end);
+            }
+        }
+    }
+    rock_map
+}
+
+fn symbol(value: &Value) -> char
+{
+    match value {
+        Value::Rock => '#',
+        Value::Sand => 'o',
+        Value::Water => 'w',
+    }
+}
+
+/// Renders the cave, handy for looking at the pile after a simulation.
+pub fn display_field(map: &SparseMap) -> String {
+    map.render('.', symbol)
+}
+
+/// What a source pours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Material
+{
+    Sand,
+    /// Falls like sand, then flows sideways along whatever it lands on until it can fall again; it turns back once
+    /// when it hits something and comes to rest when blocked on both sides.
+    Water,
+}
+
+impl Material
+{
+    fn value(self) -> Value
+    {
+        match self
+        {
+            Material::Sand => Value::Sand,
+            Material::Water => Value::Water,
+        }
+    }
+}
+
+/// A point the cave is filled from, written `x,y` or `x,y:water`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Source
+{
+    pub point: Point,
+    pub material: Material,
+}
+
+impl Source
+{
+    pub const PUZZLE: Source = Source { point: (500, 0), material: Material::Sand };
+}
+
+impl FromStr for Source
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (point, material) = match s.split_once(':')
+        {
+            Some((point, "sand")) => (point, Material::Sand),
+            Some((point, "water")) => (point, Material::Water),
+            Some((_, material)) => return Err(format!("expected sand or water, got `{}`", material)),
+            None => (s, Material::Sand),
+        };
+        let point = line_parser::point(point).map_err(|_| format!("expected a point like `500,0`, got `{}`", point))?;
+        Ok(Source { point, material })
+    }
+}
+
+/// Variants of the cave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaveOptions
+{
+    /// Each source pours one unit in turn until it is blocked or its unit is lost.
+    pub sources: Vec<Source>,
+    /// Rows between the lowest rock and an endless floor, `None` for the abyss of part 1.
+    pub floor_offset: Option<Coord>,
+    /// Columns of solid walls on the left and the right.
+    pub walls: Option<(X, X)>,
+}
+
+impl CaveOptions
+{
+    pub fn part_1() -> Self
+    {
+        CaveOptions { sources: vec![Source::PUZZLE], floor_offset: None, walls: None }
+    }
+
+    pub fn part_2() -> Self
+    {
+        CaveOptions { floor_offset: Some(2), ..Self::part_1() }
+    }
+}
+
+impl Default for CaveOptions
+{
+    fn default() -> Self
+    {
+        Self::part_1()
+    }
+}
+
+/// Geometry that doesn't change while the cave fills.
+struct Cave
+{
+    floor_position: Option<Y>,
+    walls: Option<(X, X)>,
+    /// Lowest rock, a unit below it without a floor falls forever.
+    abyss: Y,
+    /// Columns past which a unit has flowed away, relevant for water on an endless floor.
+    limits: (X, X),
+}
+
+impl Cave
+{
+    fn new(rocks: &SparseMap, options: &CaveOptions) -> Self
+    {
+        let mut area = rocks.clone();
+        for source in &options.sources
+        {
+            area.insert(source.point, Value::Sand);
+        }
+        let ((left, top), (right, bottom)) = area.bounding_box().unwrap_or(((0, 0), (0, 0)));
+        let abyss = rocks.bounding_box().map_or(0, |(_, (_, bottom))| bottom);
+        let floor_position = options.floor_offset.map(|offset| abyss + offset);
+        // Sand spreads by at most one column per row, nothing that comes to rest can get further than that.
+        let depth = floor_position.unwrap_or(bottom) - top + 1;
+        let limits = options.walls.unwrap_or((left - depth - 1, right + depth + 1));
+        Cave { floor_position, walls: options.walls, abyss, limits }
+    }
+
+    fn has_position(&self, map: &SparseMap, position: &Point) -> bool
+    {
+        if let Some(border) = self.floor_position
+        {
+            if border == position.1
+            {
+                return true;
+            }
+        }
+        if let Some((left, right)) = self.walls
+        {
+            if position.0 <= left || position.0 >= right
+            {
+                return true;
+            }
+        }
+        map.contains(*position)
+    }
+
+    fn find_min_greater_y(&self, map: &SparseMap, grain_position: &Point) -> Option<Y>
+    {
+        let surface = map.next_below(*grain_position).map(|(y, _)| y);
+        match (surface, self.floor_position)
+        {
+            (Some(y), Some(floor)) => Some(y.min(floor)),
+            (surface, floor) => surface.or(floor),
+        }
+    }
+
+    fn is_lost(&self, position: &Point) -> bool
+    {
+        (self.floor_position.is_none() && position.1 >= self.abyss) || position.0 <= self.limits.0 || position.0 >= self.limits.1
+    }
+
+    /// Where a unit poured at `source` comes to rest, `None` when it is lost.
+    fn drop_unit(&self, map: &SparseMap, source: &Source, mut path: Option<&mut Vec<Point>>) -> Option<Point>
+    {
+        let mut grain_position = source.point;
+        let mut direction = -1;
+        let mut turned = false;
+        loop
+        {
+            if let Some(path) = path.as_deref_mut()
+            {
+                path.push(grain_position);
+            }
+            if self.is_lost(&grain_position)
+            {
+                return None;
+            }
+            let down_pos = (grain_position.0, grain_position.1 + 1);
+            if !self.has_position(map, &down_pos)
+            {
+                let y_surface = self.find_min_greater_y(map, &grain_position)?;
+                if let Some(path) = path.as_deref_mut()
+                {
+                    path.extend((grain_position.1 + 1..y_surface - 1).map(|y| (grain_position.0, y)));
+                }
+                grain_position = (grain_position.0, y_surface - 1);
+                (direction, turned) = (-1, false);
+                continue;
+            }
+            let left_diagonal_pos = (grain_position.0 - 1, grain_position.1 + 1);
+            let right_diagonal_pos = (grain_position.0 + 1, grain_position.1 + 1);
+            if !self.has_position(map, &left_diagonal_pos)
+            {
+                grain_position = left_diagonal_pos;
+                (direction, turned) = (-1, false);
+                continue;
+            }
+            if !self.has_position(map, &right_diagonal_pos)
+            {
+                grain_position = right_diagonal_pos;
+                (direction, turned) = (-1, false);
+                continue;
+            }
+            if source.material == Material::Water
+            {
+                if self.has_position(map, &(grain_position.0 + direction, grain_position.1)) && !turned
+                {
+                    (direction, turned) = (-direction, true);
+                }
+                let side_pos = (grain_position.0 + direction, grain_position.1);
+                if !self.has_position(map, &side_pos)
+                {
+                    grain_position = side_pos;
+                    continue;
+                }
+            }
+            return Some(grain_position);
+        }
+    }
+}
+
+/// A grain of sand, or a unit of water, as it came to rest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grain
+{
+    pub rest: Point,
+    pub material: Material,
+    /// Cells the grain went through from the source to its resting place, empty unless paths are recorded.
+    pub path: Vec<Point>,
+}
+
+/// Collects the grains of a simulation in the order they come to rest.
+struct Recorder
+{
+    paths: bool,
+    grains: Vec<Grain>,
+}
+
+fn simulate_sand_fall(map: &mut SparseMap, cave: &Cave, sources: &[Source], mut recorder: Option<&mut Recorder>) -> usize
+{
+    let mut grain_number: usize = 0;
+    let record_path = recorder.as_ref().is_some_and(|recorder| recorder.paths);
+    let mut pouring = vec![true; sources.len()];
+    while pouring.contains(&true)
+    {
+        for (source, pouring) in sources.iter().zip(pouring.iter_mut()).filter(|(_, pouring)| **pouring)
+        {
+            if cave.has_position(map, &source.point)
+            {
+                *pouring = false;
+                continue;
+            }
+            let mut path = Vec::new();
+            let Some(grain_position) = cave.drop_unit(map, source, record_path.then_some(&mut path)) else
+            {
+                *pouring = false;
+                continue;
+            };
+            map.insert(grain_position, source.material.value());
+            if let Some(recorder) = recorder.as_deref_mut()
+            {
+                recorder.grains.push(Grain { rest: grain_position, material: source.material, path });
+            }
+            grain_number += 1;
+        }
+    }
+    grain_number
+}
+
+/// Grains of a whole simulation, to be played back one frame per grain.
+pub struct Replay
+{
+    rocks: SparseMap,
+    sources: Vec<Point>,
+    floor_position: Option<Y>,
+    walls: Option<(X, X)>,
+    grains: Vec<Grain>,
+    /// `(top left, bottom right)` corners shared by all the frames.
+    area: (Point, Point),
+}
+
+impl Replay
+{
+    /// Runs the simulation of part 1, or of part 2 when `floor` is set, and keeps every grain.
+    pub fn record(input: &Data, floor: bool, paths: bool) -> Self
+    {
+        Self::record_cave(input, &if floor { CaveOptions::part_2() } else { CaveOptions::part_1() }, paths)
+    }
+
+    /// Runs the simulation of a variant of the cave and keeps every grain.
+    pub fn record_cave(input: &Data, options: &CaveOptions, paths: bool) -> Self
+    {
+        let rocks = input_to_rock_map(input);
+        let cave = Cave::new(&rocks, options);
+        let mut map = rocks.clone();
+        let mut recorder = Recorder { paths, grains: Vec::new() };
+        simulate_sand_fall(&mut map, &cave, &options.sources, Some(&mut recorder));
+
+        for source in &options.sources
+        {
+            map.insert(source.point, Value::Sand);
+        }
+        let ((left, top), (right, bottom)) = map.bounding_box().unwrap_or(((0, 0), (0, 0)));
+        let (left, right) = match options.walls
+        {
+            Some((left_wall, right_wall)) => (left_wall, right_wall),
+            None => (left - 1, right + 1),
+        };
+        let area = ((left, top), (right, cave.floor_position.unwrap_or(bottom)));
+        let sources = options.sources.iter().map(|source| source.point).collect();
+        Replay { rocks, sources, floor_position: cave.floor_position, walls: options.walls, grains: recorder.grains, area }
+    }
+
+    pub fn grains(&self) -> &[Grain]
+    {
+        &self.grains
+    }
+
+    /// Frames after 0, `every`, `2 * every`, ... grains and after the last grain, each with the number of grains
+    /// at rest. The path of the latest grain is drawn with `~`.
+    pub fn frames(&self, every: usize) -> impl Iterator<Item = (usize, String)> + '_
+    {
+        let mut map = self.rocks.clone();
+        let mut shown = 0;
+        (0..=self.grains.len())
+            .filter(move |&count| count % every == 0 || count == self.grains.len())
+            .map(move |count|
+            {
+                for grain in &self.grains[shown..count]
+                {
+                    map.insert(grain.rest, grain.material.value());
+                }
+                shown = count;
+                (count, self.render_frame(&map, count.checked_sub(1).map(|index| &self.grains[index])))
+            })
+    }
+
+    fn render_frame(&self, map: &SparseMap, latest: Option<&Grain>) -> String
+    {
+        let mut frame = map.clone();
+        let ((left, top), (right, bottom)) = self.area;
+        if let Some(floor) = self.floor_position
+        {
+            for x in left..=right
+            {
+                frame.insert((x, floor), Value::Rock);
+            }
+        }
+        if let Some((left_wall, right_wall)) = self.walls
+        {
+            for y in top..=bottom
+            {
+                frame.insert((left_wall, y), Value::Rock);
+                frame.insert((right_wall, y), Value::Rock);
+            }
+        }
+        let path: HashSet<Point> = latest.map_or_else(HashSet::new, |grain| grain.path.iter().copied().collect());
+        let rendered = frame.render_area(self.area, '.', symbol);
+        rendered.lines().zip(top..=bottom)
+            .map(|(line, y)| line.chars().zip(left..=right)
+                .map(|(symbol, x)|
+                {
+                    if symbol == '.' && self.sources.contains(&(x, y)) { '+' }
+                    else if symbol == '.' && path.contains(&(x, y)) { '~' }
+                    else { symbol }
+                })
+                .collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn calculate_floor_position(map: & SparseMap) -> Y
+{
+    map.bounding_box().map_or(0, |(_, (_, bottom))| bottom) + 2
+}
+
+/// The cave once the sand has stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pile
+{
+    /// Grains, or units of water, at rest.
+    pub grains: usize,
+    /// Rocks, sand and water.
+    pub map: SparseGrid<Value>,
+}
+
+impl Pile
+{
+    fn new(rocks: &SparseMap, map: SparseMap) -> Self
+    {
+        Pile { grains: map.len() - rocks.len(), map }
+    }
+}
+
+/// Drops the grains one at a time, as the puzzle describes it.
+pub fn simulate(input: &Data, floor: bool) -> Pile
+{
+    simulate_cave(input, &if floor { CaveOptions::part_2() } else { CaveOptions::part_1() })
+}
+
+/// Pours every source of a variant of the cave one unit at a time until none of them can pour any more.
+pub fn simulate_cave(input: &Data, options: &CaveOptions) -> Pile
+{
+    let rocks = input_to_rock_map(input);
+    let cave = Cave::new(&rocks, options);
+    let mut map = rocks.clone();
+    simulate_sand_fall(&mut map, &cave, &options.sources, None);
+    Pile::new(&rocks, map)
+}
+
+/// Comes to the same pile as [`simulate`] visiting every cell at most a few times.
+///
+/// Without a floor, the path of the previous grain is kept on a stack and the next grain starts from where that
+/// path last had a choice. With a floor, every cell the source can reach ends up with sand, so the pile is filled
+/// row by row from the cells above.
+pub fn fill(input: &Data, floor: bool) -> Pile
+{
+    let rocks = input_to_rock_map(input);
+    let map = if floor
+    {
+        fill_rows(&rocks, calculate_floor_position(&rocks))
+    }
+    else
+    {
+        fill_along_path(&rocks)
+    };
+    Pile::new(&rocks, map)
+}
+
+fn fill_along_path(rocks: &SparseMap) -> SparseMap
+{
+    let mut map = rocks.clone();
+    let abyss = rocks.bounding_box().map_or(0, |(_, (_, bottom))| bottom);
+    let mut path = vec![(500, 0)];
+    while let Some(&(x, y)) = path.last()
+    {
+        if y >= abyss
+        {
+            // Every further grain follows this path into the abyss.
+            break;
+        }
+        match [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)].into_iter().find(|&next| !map.contains(next))
+        {
+            Some(next) => path.push(next),
+            None =>
+            {
+                map.insert((x, y), Value::Sand);
+                path.pop();
+            }
+        }
+    }
+    map
+}
+
+fn fill_rows(rocks: &SparseMap, floor_position: Y) -> SparseMap
+{
+    let mut map = rocks.clone();
+    let mut row: Vec<X> = if rocks.contains((500, 0)) { vec![] } else { vec![500] };
+    for y in 0..floor_position
+    {
+        for &x in &row
+        {
+            map.insert((x, y), Value::Sand);
+        }
+        let mut next_row: Vec<X> = row.iter()
+            .flat_map(|&x| [x - 1, x, x + 1])
+            .filter(|&x| y + 1 < floor_position && !rocks.contains((x, y + 1)))
+            .collect();
+        next_row.sort_unstable();
+        next_row.dedup();
+        row = next_row;
+    }
+    map
+}
+
+/// How the grains are poured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandSolver
+{
+    /// One grain at a time, see [`simulate`].
+    Simulation,
+    /// See [`fill`].
+    Fill,
+}
+
+pub struct Day14
+{
+    pub solver: SandSolver,
+}
+
+impl Default for Day14
+{
+    fn default() -> Self
+    {
+        Day14 { solver: SandSolver::Fill }
+    }
+}
+
+impl Day14
+{
+    pub fn with_solver(mut self, solver: SandSolver) -> Self
+    {
+        self.solver = solver;
+        self
+    }
+
+    fn pour(&self, input: &Data, floor: bool) -> Pile
+    {
+        match self.solver
+        {
+            SandSolver::Simulation => simulate(input, floor),
+            SandSolver::Fill => fill(input, floor),
+        }
+    }
+}
+
+impl Solution for Day14
+{
+    type Input = Data;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        self.pour(input, false).grains.into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        self.pour(input, true).grains.into()
+    }
+}
@@ -0,0 +1,91 @@
+//! Pictures of the sensors' coverage to check the geometry by eye.
+
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+use super::{calculate_manhattan_distance, is_covered, Coord, Data, Point};
+
+/// Smallest `(top left, bottom right)` corners holding the search box and every diamond.
+fn extent(input: &Data, search_box: &RangeInclusive<Coord>) -> (Point, Point)
+{
+    let (low, high) = (*search_box.start(), *search_box.end());
+    input.iter().fold(((low, low), (high, high)), |((left, top), (right, bottom)), pair|
+    {
+        let radius = calculate_manhattan_distance(pair);
+        let (x, y) = pair.sensor;
+        ((left.min(x - radius), top.min(y - radius)), (right.max(x + radius), bottom.max(y + radius)))
+    })
+}
+
+/// SVG of the diamonds covered by the sensors, the sensors with lines to their beacons, the search box and `gap`.
+pub fn svg(input: &Data, search_box: &RangeInclusive<Coord>, gap: Option<Point>) -> String
+{
+    let ((left, top), (right, bottom)) = extent(input, search_box);
+    let size = (right - left).max(bottom - top).max(1);
+    let margin = size / 20 + 1;
+    // Markers scale with the picture so that they stay visible for the 4000000 box as well as for the example.
+    let marker = size as f64 / 150.0;
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="800" height="800">"#,
+        left - margin, top - margin, right - left + 2 * margin, bottom - top + 2 * margin).unwrap();
+    writeln!(svg, r##"  <rect x="{0}" y="{1}" width="{2}" height="{2}" fill="none" stroke="#000" stroke-dasharray="4 4" vector-effect="non-scaling-stroke"/>"##,
+        search_box.start(), search_box.start(), search_box.end() - search_box.start()).unwrap();
+    for (index, pair) in input.iter().enumerate()
+    {
+        let radius = calculate_manhattan_distance(pair);
+        let (x, y) = pair.sensor;
+        writeln!(svg, r#"  <polygon points="{},{} {},{} {},{} {},{}" fill="hsl({}, 70%, 50%)" fill-opacity="0.3" stroke="hsl({}, 70%, 35%)" vector-effect="non-scaling-stroke"/>"#,
+            x, y - radius, x + radius, y, x, y + radius, x - radius, y, index * 47 % 360, index * 47 % 360).unwrap();
+    }
+    for pair in input
+    {
+        let ((x, y), (beacon_x, beacon_y)) = (pair.sensor, pair.beacon);
+        writeln!(svg, r##"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#555" vector-effect="non-scaling-stroke"/>"##, x, y, beacon_x, beacon_y).unwrap();
+        writeln!(svg, r##"  <circle cx="{}" cy="{}" r="{}" fill="#1f4e9c"><title>sensor {},{}</title></circle>"##, x, y, marker, x, y).unwrap();
+        writeln!(svg, r##"  <rect x="{}" y="{}" width="{}" height="{}" fill="#2a8f3a"><title>beacon {},{}</title></rect>"##,
+            beacon_x as f64 - marker, beacon_y as f64 - marker, 2.0 * marker, 2.0 * marker, beacon_x, beacon_y).unwrap();
+    }
+    if let Some((x, y)) = gap
+    {
+        writeln!(svg, r##"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="#d00" stroke-width="3" vector-effect="non-scaling-stroke"><title>gap {},{}</title></circle>"##,
+            x, y, 2.0 * marker, x, y).unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// The search box on at most `columns` columns and as many rows, in the puzzle's notation: `S` and `B` for the
+/// sensors and beacons, `#` where a beacon can't be, `.` elsewhere and `X` for `gap`.
+///
+/// When the box is shrunk, each character stands for a block of positions and shows what is at its top left one,
+/// except that sensors, beacons and the gap anywhere in the block win.
+pub fn ascii(input: &Data, search_box: &RangeInclusive<Coord>, gap: Option<Point>, columns: usize) -> String
+{
+    let low = *search_box.start();
+    let width = search_box.end() - low + 1;
+    let scale = (width + columns as Coord - 1) / columns.max(1) as Coord;
+    let cells = (width + scale - 1) / scale;
+    let block = |point: Point| ((point.0 - low) / scale, (point.1 - low) / scale);
+    let in_box = |point: &Point| search_box.contains(&point.0) && search_box.contains(&point.1);
+
+    let mut map = vec![vec!['.'; cells as usize]; cells as usize];
+    for (row, y) in (0..cells).zip((low..).step_by(scale as usize))
+    {
+        for (column, x) in (0..cells).zip((low..).step_by(scale as usize))
+        {
+            if is_covered(input, (x, y))
+            {
+                map[row as usize][column as usize] = '#';
+            }
+        }
+    }
+    let marks = input.iter().map(|pair| (pair.beacon, 'B'))
+        .chain(input.iter().map(|pair| (pair.sensor, 'S')))
+        .chain(gap.map(|gap| (gap, 'X')));
+    for (point, mark) in marks.filter(|(point, _)| in_box(point))
+    {
+        let (column, row) = block(point);
+        map[row as usize][column as usize] = mark;
+    }
+    map.iter().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>().join("\n")
+}
@@ -0,0 +1,263 @@
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, Ordering};
+use peg;
+use rayon::prelude::*;
+
+pub mod export;
+
+use crate::bench::{self, BenchOptions, Speedup};
+use crate::common::interval_set::IntervalSet;
+use crate::error::{numbered_lines, ParseError};
+use crate::solution::{Answer, Solution};
+
+type X = i64;
+type Y = i64;
+type Coord = i64;
+type Point = (X, Y);
+type Sensor = Point;
+type Beacon = Point;
+#[derive(Debug)]
+pub struct ClosestPair
+{
+    sensor: Sensor,
+    beacon: Beacon
+}
+type Data = Vec<ClosestPair>;
+
+peg::parser!{
+    grammar line_parser() for str {
+        rule coord() -> Coord
+          = n:$("-"?['0'..='9']+) {? n.parse().or(Err("coordinate")) }
+        rule point() -> Point
+          = "x=" x:coord() ", y=" y:coord() { (x,y) }
+        pub rule line() -> ClosestPair
+          = "Sensor at " sensor:point()": closest beacon is at " beacon:point() { ClosestPair{sensor, beacon} }
+    }
+}
+
+fn read_input(contents: &str) -> Result<Data, ParseError>
+{
+    numbered_lines(contents).map(|(number, line)|
+    {
+        line_parser::line(line).map_err(|error| ParseError::from_peg(number, line, error))
+    }).collect()
+}
+
+fn calculate_manhattan_distance(pair: &ClosestPair)-> i64
+{
+    (pair.sensor.0 - pair.beacon.0).abs() + (pair.sensor.1 - pair.beacon.1).abs()
+}
+
+fn find_ranges_of_detection(data: &Data, row_index: i64) -> IntervalSet<Coord>
+{
+    let mut covering_ranges = IntervalSet::new();
+    fill_ranges_of_detection(data, row_index, &mut covering_ranges);
+    covering_ranges
+}
+
+/// Same as [`find_ranges_of_detection`] into a set that is cleared first, so that its memory is reused.
+fn fill_ranges_of_detection(data: &Data, row_index: i64, covering_ranges: &mut IntervalSet<Coord>)
+{
+    covering_ranges.clear();
+    for pair in data
+    {
+        let distance = calculate_manhattan_distance(pair);
+        let difference_by_y = (row_index - pair.sensor.1).abs() - distance;
+        if difference_by_y <= 0
+        {
+            covering_ranges.insert(pair.sensor.0 - difference_by_y.abs(), pair.sensor.0 + difference_by_y.abs());
+        }
+        if row_index == pair.beacon.1
+        {
+            covering_ranges.insert(pair.beacon.0, pair.beacon.0);
+        }
+    }
+}
+
+fn calculate_beacons_in_row(data: &Data, row_index: i64) -> i64
+{
+    let mut beacons_in_row: BTreeSet<Beacon> = BTreeSet::new();
+    for pair in data
+    {
+        if row_index == pair.beacon.1
+        {
+            beacons_in_row.insert(pair.beacon);
+        }
+    }
+    beacons_in_row.len() as i64
+}
+
+fn count_positions_without_beacon(input: &Data, row_index: i64) -> i64
+{
+    find_ranges_of_detection(input, row_index).len() as i64 - calculate_beacons_in_row(input, row_index)
+}
+
+fn is_covered(input: &Data, point: Point) -> bool
+{
+    input.iter().any(|pair|
+        (pair.sensor.0 - point.0).abs() + (pair.sensor.1 - point.1).abs() <= calculate_manhattan_distance(pair))
+}
+
+/// The only uncovered position has to lie just outside the diamonds of several sensors, so it is
+/// an intersection of their boundary lines `x + y = a` and `x - y = b` (or a corner of the box).
+fn find_beacon_by_boundaries(input: &Data, search_box: &RangeInclusive<Coord>) -> Option<Point>
+{
+    let mut ascending: BTreeSet<Coord> = BTreeSet::new();
+    let mut descending: BTreeSet<Coord> = BTreeSet::new();
+    for pair in input
+    {
+        let radius = calculate_manhattan_distance(pair) + 1;
+        let (x, y) = pair.sensor;
+        ascending.extend([x + y - radius, x + y + radius]);
+        descending.extend([x - y - radius, x - y + radius]);
+    }
+
+    let (low, high) = (*search_box.start(), *search_box.end());
+    let corners = [(low, low), (low, high), (high, low), (high, high)];
+    let intersections = ascending.iter()
+        .flat_map(|&a| descending.iter().map(move |&b| (a, b)))
+        .filter(|(a, b)| (a + b) % 2 == 0)
+        .map(|(a, b)| ((a + b) / 2, (a - b) / 2));
+    intersections.chain(corners)
+        .filter(|(x, y)| search_box.contains(x) && search_box.contains(y))
+        .find(|&point| !is_covered(input, point))
+}
+
+/// First row of `rows` with a gap in the search box, reusing `covering_ranges` for every row. Gives up as soon as
+/// `found` is set by another worker.
+fn scan_rows(input: &Data, search_box: &RangeInclusive<Coord>, rows: RangeInclusive<Coord>, covering_ranges: &mut IntervalSet<Coord>, found: &AtomicBool) -> Option<Point>
+{
+    for y in rows
+    {
+        if found.load(Ordering::Relaxed)
+        {
+            return None;
+        }
+        fill_ranges_of_detection(input, y, covering_ranges);
+        if let Some(x) = covering_ranges.first_gap_within(*search_box.start(), *search_box.end())
+        {
+            found.store(true, Ordering::Relaxed);
+            return Some((x, y));
+        }
+    }
+    None
+}
+
+fn find_beacon_by_row_scan(input: &Data, search_box: &RangeInclusive<Coord>) -> Option<Point>
+{
+    scan_rows(input, search_box, search_box.clone(), &mut IntervalSet::new(), &AtomicBool::new(false))
+}
+
+/// Rows a worker of the parallel scan takes at once.
+const ROWS_PER_CHUNK: Coord = 4096;
+
+/// The row scan with chunks of rows spread over the threads of the current rayon pool.
+fn find_beacon_by_parallel_row_scan(input: &Data, search_box: &RangeInclusive<Coord>) -> Option<Point>
+{
+    let (low, high) = (*search_box.start(), *search_box.end());
+    if high < low
+    {
+        return None;
+    }
+    // Tells the other workers to give up their chunks once one of them finds the gap.
+    let found = AtomicBool::new(false);
+    (0..=(high - low) / ROWS_PER_CHUNK).into_par_iter()
+        .map_init(IntervalSet::new, |covering_ranges, chunk|
+        {
+            let first_row = low + chunk * ROWS_PER_CHUNK;
+            scan_rows(input, search_box, first_row..=(first_row + ROWS_PER_CHUNK - 1).min(high), covering_ranges, &found)
+        })
+        .find_map_any(|gap| gap)
+}
+
+fn find_distress_beacon(input: &Data, search_box: &RangeInclusive<Coord>, search: BeaconSearch) -> Option<Point>
+{
+    match search
+    {
+        BeaconSearch::Geometric => find_beacon_by_boundaries(input, search_box)
+            .or_else(|| find_beacon_by_row_scan(input, search_box)),
+        BeaconSearch::RowScan => find_beacon_by_row_scan(input, search_box),
+        BeaconSearch::ParallelRowScan => find_beacon_by_parallel_row_scan(input, search_box),
+    }
+}
+
+/// How part 2 looks for the distress beacon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeaconSearch
+{
+    /// Checks the intersections of the sensors' boundaries, scans the rows when none of them fits.
+    Geometric,
+    /// Looks for a gap in every row of the search box.
+    RowScan,
+    /// Same as `RowScan` with chunks of rows spread over rayon's threads.
+    ParallelRowScan,
+}
+
+pub struct Day15
+{
+    /// Row in which part 1 counts the positions where the beacon cannot be.
+    pub row_index: Y,
+    /// Part 2 looks for the beacon with both coordinates in this range.
+    pub search_box: RangeInclusive<Coord>,
+    /// The tuning frequency is `x * tuning_multiplier + y`.
+    pub tuning_multiplier: Coord,
+    pub search: BeaconSearch,
+}
+
+impl Day15
+{
+    /// The example asks the same questions about a much smaller area.
+    pub fn example() -> Self
+    {
+        Day15 { row_index: 10, search_box: 0..=20, ..Self::default() }
+    }
+
+    pub fn with_search(self, search: BeaconSearch) -> Self
+    {
+        Day15 { search, ..self }
+    }
+
+    /// The only position of the search box no sensor covers.
+    pub fn distress_beacon(&self, input: &Data) -> Option<Point>
+    {
+        find_distress_beacon(input, &self.search_box, self.search)
+    }
+}
+
+impl Default for Day15
+{
+    fn default() -> Self
+    {
+        Day15 { row_index: 2000000, search_box: 0..=4000000, tuning_multiplier: 4000000, search: BeaconSearch::Geometric }
+    }
+}
+
+impl Solution for Day15
+{
+    type Input = Data;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>
+    {
+        read_input(contents)
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Answer
+    {
+        count_positions_without_beacon(input, self.row_index).into()
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Answer
+    {
+        match self.distress_beacon(input)
+        {
+            Some((x, y)) => (x * self.tuning_multiplier + y).into(),
+            None => Answer::Error("every position of the search box is covered by a sensor".to_string()),
+        }
+    }
+
+    fn speedups(&self, input: &Self::Input, options: BenchOptions) -> Vec<Speedup>
+    {
+        vec![bench::measure_speedup(options, "part_2 parallel row scan", || find_beacon_by_parallel_row_scan(input, &self.search_box))]
+    }
+}
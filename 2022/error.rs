@@ -0,0 +1,90 @@
+use std::error::Error;
+use std::fmt;
+
+/// Malformed puzzle input, pointing at the place where parsing stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError
+{
+    /// Set by the runner, parsers don't need to know which day they belong to.
+    pub day: Option<u8>,
+    /// 1-based line of the input.
+    pub line: usize,
+    /// 1-based character column within the line.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError
+{
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self
+    {
+        ParseError { day: None, line, column, text: text.to_string(), message: message.into() }
+    }
+
+    /// Points at `token`, which has to be a slice of `text`; falls back to the start of the line otherwise.
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self
+    {
+        let start = text.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let column = if (start..=start + text.len()).contains(&token_start)
+        {
+            text[..token_start - start].chars().count() + 1
+        }
+        else
+        {
+            1
+        };
+        ParseError::new(line, column, text, message)
+    }
+
+    /// Converts an error of one of the `peg` grammars run over `text`, which starts at `line` of the input.
+    pub fn from_peg(line: usize, text: &str, error: peg::error::ParseError<peg::str::LineCol>) -> Self
+    {
+        let error_line = line + error.location.line - 1;
+        let error_text = text.lines().nth(error.location.line - 1).unwrap_or_default();
+        ParseError::new(error_line, error.location.column, error_text, format!("expected {}", error.expected))
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self
+    {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if let Some(day) = self.day
+        {
+            write!(f, "day {:02}, ", day)?;
+        }
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {}^", " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Non-empty lines of the input together with their 1-based line numbers.
+pub fn numbered_lines(contents: &str) -> impl Iterator<Item = (usize, &str)>
+{
+    contents.lines().enumerate().map(|(index, line)| (index + 1, line)).filter(|(_, line)| !line.is_empty())
+}
+
+/// Blocks of the input separated by empty lines, together with the 1-based number of their first line.
+pub fn numbered_blocks(contents: &str) -> impl Iterator<Item = (usize, &str)>
+{
+    let mut line = 1;
+    contents.split("\n\n").filter_map(move |block|
+    {
+        let first_line = line + block.len() - block.trim_start_matches('\n').len();
+        line += block.matches('\n').count() + 2;
+        let block = block.trim_matches('\n');
+        (!block.is_empty()).then_some((first_line, block))
+    })
+}
@@ -0,0 +1,73 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory laid out like this crate (`day12/input.txt`, `day12/test_input.txt`, ...)
+/// that is searched for inputs when no explicit file is given.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const STDIN_PATH: &str = "-";
+
+/// Where a day reads its input from.
+///
+/// An explicit `path` wins; otherwise the day's file is looked up in `$AOC_INPUT_DIR`
+/// and finally next to the sources of this crate, so the runner works from any directory.
+#[derive(Debug, Clone, Default)]
+pub struct InputSource
+{
+    /// Input file, `-` for stdin.
+    pub path: Option<PathBuf>,
+    /// Use the example from the puzzle description instead of the personal input.
+    pub example: bool,
+}
+
+impl InputSource
+{
+    pub fn puzzle() -> Self
+    {
+        InputSource { path: None, example: false }
+    }
+
+    pub fn example() -> Self
+    {
+        InputSource { path: None, example: true }
+    }
+
+    /// `None` stands for stdin.
+    pub fn resolve(&self, day: u8) -> Option<PathBuf>
+    {
+        match &self.path
+        {
+            Some(path) if path == Path::new(STDIN_PATH) => None,
+            Some(path) => Some(path.clone()),
+            None =>
+            {
+                let file_name = if self.example { "test_input.txt" } else { "input.txt" };
+                Some(input_dir().join(format!("day{:02}", day)).join(file_name))
+            }
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String>
+    {
+        match self.resolve(day)
+        {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error))),
+            None =>
+            {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            }
+        }
+    }
+}
+
+pub fn input_dir() -> PathBuf
+{
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::str::FromStr;
+
+use solution::Solver;
+
+pub mod bench;
+pub mod common;
+pub mod error;
+pub mod input;
+pub mod solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part
+{
+    One,
+    Two,
+}
+
+impl Part
+{
+    /// `None` selects both parts.
+    pub fn is_selected(self, selection: Option<Part>) -> bool
+    {
+        selection.is_none_or(|part| part == self)
+    }
+}
+
+impl FromStr for Part
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        match s
+        {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got `{}`", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub struct Day
+{
+    pub number: u8,
+    pub solution: Box<dyn Solver>,
+    /// Used on the example when the puzzle asks a different question about it.
+    pub example_solution: Option<Box<dyn Solver>>,
+}
+
+impl Day
+{
+    fn new(number: u8, solution: impl Solver + 'static) -> Self
+    {
+        Day { number, solution: Box::new(solution), example_solution: None }
+    }
+
+    fn with_example(mut self, solution: impl Solver + 'static) -> Self
+    {
+        self.example_solution = Some(Box::new(solution));
+        self
+    }
+
+    pub fn solution(&self, example: bool) -> &dyn Solver
+    {
+        match &self.example_solution
+        {
+            Some(example_solution) if example => example_solution.as_ref(),
+            _ => self.solution.as_ref(),
+        }
+    }
+}
+
+fn days_2022() -> Vec<Day>
+{
+    vec![
+        Day::new(1, day01::Day01),
+        Day::new(2, day02::Day02),
+        Day::new(3, day03::Day03),
+        Day::new(4, day04::Day04),
+        Day::new(5, day05::Day05),
+        Day::new(6, day06::Day06),
+        Day::new(7, day07::Day07),
+        Day::new(8, day08::Day08),
+        Day::new(9, day09::Day09),
+        Day::new(10, day10::Day10::default()).with_example(day10::Day10::example()),
+        Day::new(11, day11::Day11::default()),
+        Day::new(12, day12::Day12),
+        Day::new(13, day13::Day13),
+        Day::new(14, day14::Day14::default()),
+        Day::new(15, day15::Day15::default()).with_example(day15::Day15::example()),
+    ]
+}
+
+/// All solved days of the given year, ordered by day number.
+pub fn registry(year: u16) -> Option<Vec<Day>>
+{
+    match year
+    {
+        2022 => Some(days_2022()),
+        _ => None,
+    }
+}
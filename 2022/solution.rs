@@ -0,0 +1,120 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::bench::{BenchOptions, Speedup};
+use crate::error::ParseError;
+use crate::Part;
+
+/// What a puzzle part evaluates to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer
+{
+    Integer(i64),
+    Text(String),
+    /// A picture the puzzle asks to read, rows separated by `\n`.
+    Grid(String),
+    /// Why the part can't be worked out from an input that parsed, e.g. an overflow.
+    Error(String),
+}
+
+impl fmt::Display for Answer
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Answer::Integer(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(picture) => write!(f, "{}", picture),
+            Answer::Error(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer
+            {
+                fn from(number: $t) -> Self
+                {
+                    Answer::Integer(i64::try_from(number).expect("answer does not fit into i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer
+{
+    fn from(text: String) -> Self
+    {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer
+{
+    fn from(text: &str) -> Self
+    {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// A day of the calendar: the input is parsed once and both parts work on the parsed form.
+pub trait Solution
+{
+    type Input;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(&self, input: &Self::Input) -> Answer;
+    fn part_2(&self, input: &Self::Input) -> Answer;
+
+    /// Steps that `aoc bench` also measures on one thread and on all of rayon's threads.
+    fn speedups(&self, _input: &Self::Input, _options: BenchOptions) -> Vec<Speedup>
+    {
+        Vec::new()
+    }
+}
+
+/// Object-safe form of [`Solution`] with the parsed input type erased, so that
+/// different days can live side by side in the registry.
+pub trait Solver
+{
+    fn parse_any(&self, contents: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+    fn speedups_any(&self, input: &dyn Any, options: BenchOptions) -> Vec<Speedup>;
+
+    fn run(&self, contents: &str, part: Part) -> Result<Answer, ParseError>
+    {
+        Ok(self.solve(self.parse_any(contents)?.as_ref(), part))
+    }
+}
+
+impl<S> Solver for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse_any(&self, contents: &str) -> Result<Box<dyn Any>, ParseError>
+    {
+        Ok(Box::new(self.parse(contents)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer
+    {
+        let input = input.downcast_ref::<S::Input>().expect("input was parsed by another solution");
+        match part
+        {
+            Part::One => self.part_1(input),
+            Part::Two => self.part_2(input),
+        }
+    }
+
+    fn speedups_any(&self, input: &dyn Any, options: BenchOptions) -> Vec<Speedup>
+    {
+        self.speedups(input.downcast_ref::<S::Input>().expect("input was parsed by another solution"), options)
+    }
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use advent_of_code::bench::{self, BenchOptions, Timings};
+use advent_of_code::registry;
+
+#[test]
+fn timings_summarize_the_samples()
+{
+    let samples = [40, 10, 30, 20].map(Duration::from_millis).to_vec();
+    let timings = Timings::from_samples(samples);
+    assert_eq!(timings.min, Duration::from_millis(10));
+    assert_eq!(timings.median, Duration::from_millis(25));
+    assert_eq!(timings.mean, Duration::from_millis(25));
+}
+
+#[test]
+fn json_report_has_every_step_of_every_day()
+{
+    let days = registry(2022).unwrap();
+    let day = days.iter().find(|day| day.number == 1).unwrap();
+    let options = BenchOptions { warmup: 0, iterations: 2 };
+    let result = bench::bench_day(1, day.solution(true), "1\n2\n\n3\n\n4\n", options).unwrap();
+
+    let report = bench::to_json(2022, options, &[result]);
+    assert_eq!(report["iterations"], 2);
+    for step in ["parse", "part_1", "part_2"]
+    {
+        assert!(report["days"][0][step]["median_ns"].is_u64(), "{} is missing", step);
+    }
+}
+
+#[test]
+fn days_report_their_speedups()
+{
+    let days = registry(2022).unwrap();
+    let options = BenchOptions { warmup: 0, iterations: 1 };
+    let contents = include_str!("../day15/test_input.txt");
+    let day = days.iter().find(|day| day.number == 15).unwrap();
+    let result = bench::bench_day(15, day.solution(true), contents, options).unwrap();
+    assert_eq!(result.speedups.len(), 1);
+    assert!(result.speedups[0].threads >= 1);
+
+    let report = bench::to_json(2022, options, &[result]);
+    assert!(report["days"][0]["speedups"][0]["speedup"].is_f64());
+    let day = days.iter().find(|day| day.number == 1).unwrap();
+    assert!(bench::bench_day(1, day.solution(true), "1\n2\n\n3\n\n4\n", options).unwrap().speedups.is_empty());
+}
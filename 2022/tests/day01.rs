@@ -0,0 +1,16 @@
+use advent_of_code::day01::Day01;
+use advent_of_code::solution::Solution;
+
+#[test]
+fn fewer_than_three_elves()
+{
+    let input = Day01.parse("1\n\n2\n").unwrap();
+    assert_eq!(Day01.part_2(&input).to_string(), "3");
+}
+
+#[test]
+fn overflowing_calories_are_parse_errors()
+{
+    let error = Day01.parse("5\n\n2147483647\n1\n").unwrap_err();
+    assert_eq!((error.line, error.text.as_str()), (4, "1"));
+}
@@ -0,0 +1,28 @@
+use advent_of_code::day05::Day05;
+use advent_of_code::solution::Solution;
+
+#[test]
+fn too_large_numbers_are_parse_errors()
+{
+    let error = Day05.parse("[A]\n 1 \n\nmove 99999999999999999999999 from 1 to 1\n").err().unwrap();
+    assert_eq!((error.line, error.column), (4, 6));
+    let error = Day05.parse("[A]\n 1 \n\nmove 1 from 1 to 99999999999999999999999\n").err().unwrap();
+    assert_eq!((error.line, error.column), (4, 18));
+}
+
+#[test]
+fn malformed_stack_labels_are_parse_errors()
+{
+    let error = Day05.parse("[A]\n x \n\nmove 1 from 1 to 1\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.text, " x ");
+}
+
+#[test]
+fn moves_cannot_take_more_crates_than_the_stack_holds()
+{
+    let drawing = "[A]    \n[B] [C]\n 1   2 \n\n";
+    assert!(Day05.parse(&format!("{}move 2 from 1 to 2\nmove 3 from 2 to 1\n", drawing)).is_ok());
+    let error = Day05.parse(&format!("{}move 2 from 1 to 2\nmove 4 from 2 to 1\n", drawing)).unwrap_err();
+    assert_eq!((error.line, error.column, error.message.as_str()), (6, 6, "stack 2 only holds 3 crates at this point"));
+}
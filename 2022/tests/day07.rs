@@ -0,0 +1,37 @@
+use advent_of_code::day07::Day07;
+use advent_of_code::solution::Solution;
+
+#[test]
+fn example_renders_like_tree()
+{
+    let file_system = Day07.parse(include_str!("../day07/test_input.txt")).unwrap();
+    assert_eq!(file_system.render_tree(), "\
+/ (48381165)
+├── a/ (94853)
+│   ├── e/ (584)
+│   │   └── i (584)
+│   ├── f (29116)
+│   ├── g (2557)
+│   └── h.lst (62596)
+├── b.txt (14848514)
+├── c.dat (8504156)
+└── d/ (24933642)
+    ├── d.ext (5626152)
+    ├── d.log (8033020)
+    ├── j (4060174)
+    └── k (7214296)");
+}
+
+#[test]
+fn transcript_errors_point_at_the_offending_line()
+{
+    let error = |transcript: &str|
+    {
+        let error = Day07.parse(transcript).unwrap_err();
+        (error.line, error.column, error.message)
+    };
+    assert_eq!(error("$ cd /\n$ cd ..\n"), (2, 6, "the root directory has no parent".to_string()));
+    assert_eq!(error("$ ls\n100 a\n$ cd a\n"), (3, 6, "not a directory".to_string()));
+    assert_eq!(error("$ ls\n$ pwd\n"), (2, 3, "unknown command, expected `cd` or `ls`".to_string()));
+    assert_eq!(error("$ ls\ndir a\n$ cd a\n100 b\n"), (4, 1, "expected a command starting with `$`".to_string()));
+}
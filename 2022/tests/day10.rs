@@ -0,0 +1,75 @@
+use advent_of_code::common::grid::Grid;
+use advent_of_code::day10::cpu::{self, Cpu, Instruction};
+use advent_of_code::day10::ocr;
+
+const PUZZLE_PICTURE: &str = "\
+###..#....####.####.#..#.#....###..###..
+#..#.#....#....#....#..#.#....#..#.#..#.
+#..#.#....###..###..#..#.#....#..#.###..
+###..#....#....#....#..#.#....###..#..#.
+#....#....#....#....#..#.#....#....#..#.
+#....####.####.#.....##..####.#....###..";
+
+fn picture(text: &str) -> Grid<bool>
+{
+    Grid::parse(text, "`#` or `.`", |ch| match ch
+    {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }).unwrap()
+}
+
+#[test]
+fn decodes_the_letters()
+{
+    assert_eq!(ocr::decode(&picture(PUZZLE_PICTURE)).unwrap(), "PLEFULPB");
+}
+
+#[test]
+fn reports_unknown_glyphs_with_their_bitmap()
+{
+    let stripes = "\
+#..#.####.
+##...####.
+#....####.
+#....####.
+#....####.
+#....####.";
+    let error = ocr::decode(&picture(stripes)).unwrap_err();
+    assert_eq!(error.index, 0);
+    assert_eq!(error.bitmap.lines().next(), Some("#..#"));
+}
+
+#[test]
+fn cpu_reports_x_during_every_cycle()
+{
+    let program = cpu::parse_program("noop\naddx 3\naddx -5\n").unwrap();
+    assert_eq!(program, [Instruction::Noop, Instruction::Addx(3), Instruction::Addx(-5)]);
+    let states: Vec<(usize, i64)> = Cpu::new(&program).map(|state| (state.cycle, state.x)).collect();
+    assert_eq!(states, [(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+}
+
+#[test]
+fn cpu_stops_at_breakpoints()
+{
+    let program = cpu::parse_program("noop\naddx 3\naddx -5\n").unwrap();
+    let mut cpu = Cpu::new(&program).with_breakpoints([2, 4, 10]);
+    assert_eq!(cpu.resume().map(|state| (state.cycle, state.instruction)), Some((2, Instruction::Addx(3))));
+    assert_eq!(cpu.resume().map(|state| (state.cycle, state.x)), Some((4, 4)));
+    assert_eq!(cpu.resume(), None);
+}
+
+#[test]
+fn program_errors_point_at_the_operand()
+{
+    let position = |program: &str|
+    {
+        let error = cpu::parse_program(program).unwrap_err();
+        (error.line, error.column)
+    };
+    assert_eq!(position("noop\naddx x\n"), (2, 6));
+    assert_eq!(position("addx\n"), (1, 5));
+    assert_eq!(position("noop\n  mulx 3\n"), (2, 3));
+    assert_eq!(position("addx 1 2\n"), (1, 8));
+}
@@ -0,0 +1,176 @@
+use num_bigint::BigInt;
+
+use advent_of_code::day11::expression::{EvaluationError, Expression};
+use advent_of_code::day11::worry::WorryType;
+use advent_of_code::day11::{monkey_business, monkey_business_by_cycles, CycleError, Day11, Relief, Simulation};
+use advent_of_code::solution::{Answer, Solution};
+
+#[test]
+fn expressions_follow_precedence_and_parentheses()
+{
+    let expression = Expression::parse_operation("new = (old + 3) % 7 * 19 - old / 2").unwrap();
+    assert_eq!(expression.evaluate(&10i64), Ok((10 + 3) % 7 * 19 - 10 / 2));
+    assert_eq!(Expression::parse("old - 1 - 2").unwrap().evaluate(&10i64), Ok(7));
+    assert_eq!(Expression::parse("old / (old - old)").unwrap().evaluate(&10i64), Err(EvaluationError::DivisionByZero));
+}
+
+#[test]
+fn expressions_print_back_to_the_input_syntax()
+{
+    for text in ["old * old", "old + 6", "(old + 3) % 7 * 19 - old / 2", "old - (1 - 2)", "(old + 1) * (old + 2)"]
+    {
+        let expression = Expression::parse(text).unwrap();
+        assert_eq!(expression.to_string(), text);
+        assert_eq!(Expression::parse(&expression.to_string()).unwrap(), expression);
+    }
+    assert_eq!(Expression::parse("((old)) * (2)").unwrap().to_string(), "old * 2");
+}
+
+#[test]
+fn unsupported_constructs_are_parse_errors()
+{
+    let error = Expression::parse_operation("new = old ^ 2").unwrap_err();
+    assert_eq!(error.location.column, 11);
+    assert!(Expression::parse("old * -2").is_err());
+    assert!(Expression::parse("new * 2").is_err());
+}
+
+#[test]
+fn worry_types_agree_on_the_example()
+{
+    let monkeys = Day11::default().parse(include_str!("../day11/test_input.txt")).unwrap();
+    for worry in [WorryType::I64, WorryType::I128, WorryType::BigInt]
+    {
+        let day = Day11::default().with_worry(worry);
+        assert_eq!(day.try_monkey_business(&monkeys, 20, Relief::DivideByThree), Ok(10605));
+        assert_eq!(day.try_monkey_business(&monkeys, 10000, Relief::ModuloDivisors), Ok(2713310158));
+    }
+}
+
+#[test]
+fn overflow_names_the_monkey_round_and_item()
+{
+    let input = "\
+Monkey 0:
+  Starting items: 7, 3000000000
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 5
+  Operation: new = old * old
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+    let monkeys = Day11::default().parse(input).unwrap();
+
+    let error = monkey_business::<i64>(&monkeys, 20, Relief::DivideByThree).unwrap_err();
+    assert_eq!((error.round, error.monkey, error.item.monkey, error.item.index), (1, 1, 0, 1));
+    assert_eq!(error.worry_level, 3000000000i64 * 3000000000 / 3);
+    assert_eq!(error.error, EvaluationError::Overflow);
+    assert_eq!(error.to_string(),
+        "round 1, monkey 1 inspecting starting item 1 of monkey 0 (worry level 3000000000000000000): overflow in `new = old * old`");
+
+    assert_eq!(Day11::default().part_1(&monkeys), Answer::Error(error.to_string()));
+    assert_eq!(monkey_business::<i128>(&monkeys, 20, Relief::DivideByThree).unwrap_err().round, 2);
+    assert_eq!(monkey_business::<i64>(&monkeys, 20, Relief::ModuloDivisors).unwrap(),
+        monkey_business::<BigInt>(&monkeys, 20, Relief::ModuloDivisors).unwrap());
+}
+
+#[test]
+fn cycles_extrapolate_the_literal_simulation()
+{
+    for contents in [include_str!("../day11/test_input.txt"), include_str!("../day11/input.txt")]
+    {
+        let monkeys = Day11::default().parse(contents).unwrap();
+        for rounds in [0, 1, 20, 1000, 10000]
+        {
+            assert_eq!(monkey_business_by_cycles::<i64>(&monkeys, rounds).unwrap(),
+                monkey_business::<i64>(&monkeys, rounds as usize, Relief::ModuloDivisors).unwrap() as u128);
+        }
+    }
+}
+
+#[test]
+fn monkeys_and_throw_targets_are_checked()
+{
+    let monkey = |number: usize, target: usize| format!("\
+Monkey {}:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 2
+    If true: throw to monkey {}
+    If false: throw to monkey 0", number, target);
+
+    let error = Day11::default().parse(&format!("{}\n\n{}", monkey(0, 1), monkey(1, 5))).unwrap_err();
+    assert_eq!((error.line, error.column, error.message.as_str()), (12, 30, "there is no monkey 5, the last one is monkey 1"));
+
+    let error = Day11::default().parse(&format!("{}\n\n{}", monkey(0, 1), monkey(2, 0))).unwrap_err();
+    assert_eq!((error.line, error.column), (8, 8));
+
+    let error = Day11::default().parse(&monkey(0, 0)).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 8));
+    assert!(Day11::default().parse("").is_err());
+}
+
+#[test]
+fn cycles_need_a_representable_divisor_product()
+{
+    let input = "\
+Monkey 0:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 4000000000
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 2
+  Operation: new = old + 1
+  Test: divisible by 4000000001
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+    let monkeys = Day11::default().parse(input).unwrap();
+    assert!(matches!(monkey_business_by_cycles::<i64>(&monkeys, 1000000000000), Err(CycleError::DivisorProductOverflow)));
+    assert_eq!(monkey_business_by_cycles::<i128>(&monkeys, 10).unwrap(), 19 * 20);
+
+    let day = Day11::default().with_simulation(Simulation::Cycles);
+    assert!(matches!(day.part_2(&monkeys), Answer::Error(_)));
+}
+
+const DIVIDING_MONKEYS: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 3 / 2
+  Test: divisible by 5
+    If true: throw to monkey 1
+    If false: throw to monkey 0
+
+Monkey 1:
+  Starting items: 54, 65
+  Operation: new = old + 7
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+#[test]
+fn division_keeps_the_worry_levels_unreduced()
+{
+    let monkeys = Day11::default().parse(DIVIDING_MONKEYS).unwrap();
+    // 798 when the worry levels are reduced modulo 15 after every inspection.
+    assert_eq!(monkey_business::<BigInt>(&monkeys, 30, Relief::ModuloDivisors).unwrap(), 1760);
+    assert_eq!(monkey_business::<i64>(&monkeys, 30, Relief::ModuloDivisors).unwrap(), 1760);
+    assert_eq!(monkey_business::<BigInt>(&monkeys, 200, Relief::ModuloDivisors).unwrap(), 66352);
+    assert_eq!(monkey_business::<i64>(&monkeys, 200, Relief::ModuloDivisors).unwrap_err().error, EvaluationError::Overflow);
+}
+
+#[test]
+fn cycles_need_operations_that_keep_remainders()
+{
+    let monkeys = Day11::default().parse(DIVIDING_MONKEYS).unwrap();
+    assert!(matches!(monkey_business_by_cycles::<BigInt>(&monkeys, 30), Err(CycleError::IrreducibleOperation(0))));
+    let day = Day11::default().with_worry(WorryType::BigInt).with_simulation(Simulation::Cycles);
+    assert!(matches!(day.part_2(&monkeys), Answer::Error(_)));
+}
@@ -0,0 +1,30 @@
+use advent_of_code::day12::{self, Day12, RouteStyle};
+use advent_of_code::solution::{Answer, Solution};
+
+#[test]
+fn routes_are_drawn_in_the_puzzle_notation()
+{
+    let height_map = Day12.parse(include_str!("../day12/test_input.txt")).unwrap();
+
+    let route = day12::route_from_start(&height_map).unwrap();
+    assert_eq!(day12::render_route(&height_map, &route, RouteStyle::Plain), "\
+S..v<<<<
+>v.vv<<^
+.>vv>E^^
+..v>>>^^
+..>>>>>^");
+
+    let route = day12::route_from_lowest(&height_map).unwrap();
+    assert_eq!(route.len() - 1, 29);
+    let rendered = day12::render_route(&height_map, &route, RouteStyle::Plain);
+    assert_eq!(rendered.chars().nth(route[0].0 * 9 + route[0].1), Some('a'));
+    assert!(day12::render_route(&height_map, &route, RouteStyle::Ansi).contains("\x1b[1;30;46ma\x1b[0m"));
+}
+
+#[test]
+fn unreachable_end_is_an_error_answer()
+{
+    let height_map = Day12.parse("Sbc\nzzE\n").unwrap();
+    assert_eq!(Day12.part_1(&height_map), Answer::Error("E can't be reached".to_string()));
+    assert_eq!(Day12.part_2(&height_map), Answer::Error("E can't be reached".to_string()));
+}
@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use proptest::prelude::*;
+
+use advent_of_code::day13::{Day13, Value};
+use advent_of_code::solution::Solution;
+
+fn packet() -> impl Strategy<Value = Value>
+{
+    let leaf = (0..100i64).prop_map(Value::Digit);
+    let value = leaf.prop_recursive(4, 32, 5, |inner| prop::collection::vec(inner, 0..5).prop_map(Value::List));
+    prop::collection::vec(value, 0..5).prop_map(Value::List)
+}
+
+proptest!
+{
+    // `==` compares packets by their order, where `[1]` equals `1`, so the structure is compared through `Debug`.
+    #[test]
+    fn printed_packets_parse_back(value in packet())
+    {
+        prop_assert_eq!(format!("{:?}", Value::parse(&value.to_string()).unwrap()), format!("{:?}", value));
+        prop_assert_eq!(format!("{:?}", Value::parse(&value.pretty()).unwrap()), format!("{:?}", value));
+        let json = serde_json::Value::from(&value);
+        prop_assert_eq!(json.to_string(), value.to_string());
+        prop_assert_eq!(format!("{:?}", Value::try_from(&json).unwrap()), format!("{:?}", value));
+    }
+
+    #[test]
+    fn explained_comparison_agrees_with_ord(left in packet(), right in packet())
+    {
+        prop_assert_eq!(left.compare_explained(&right).ordering, left.cmp(&right));
+    }
+}
+
+#[test]
+fn packets_print_like_the_input()
+{
+    let value = Value::parse("[1,[2,[3,[4,[5,6,7]]]],8,9]").unwrap();
+    assert_eq!(value.to_string(), "[1,[2,[3,[4,[5,6,7]]]],8,9]");
+    assert_eq!(value.pretty(), "\
+[
+  1,
+  [
+    2,
+    [
+      3,
+      [
+        4,
+        [5,6,7]
+      ]
+    ]
+  ],
+  8,
+  9
+]");
+    assert_eq!(Value::parse("[[]]").unwrap().pretty(), "[\n  []\n]");
+    assert!(Value::try_from(&serde_json::json!([1, [-2]])).is_err());
+    assert!(Value::try_from(&serde_json::json!([1, "2"])).is_err());
+}
+
+#[test]
+fn comparisons_are_explained_like_the_puzzle()
+{
+    let pairs = Day13.parse(include_str!("../day13/test_input.txt")).unwrap();
+    assert_eq!(pairs[2][0].compare_explained(&pairs[2][1]).to_string(), "\
+- Compare [9] vs [[8,7,6]]
+  - Compare 9 vs [8,7,6]
+    - Mixed types; convert left to [9] and retry comparison
+    - Compare [9] vs [8,7,6]
+      - Compare 9 vs 8
+        - Right side is smaller, so inputs are not in the right order");
+    assert_eq!(pairs[3][0].compare_explained(&pairs[3][1]).to_string(), "\
+- Compare [[4,4],4,4] vs [[4,4],4,4,4]
+  - Compare [4,4] vs [4,4]
+    - Compare 4 vs 4
+    - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Left side ran out of items, so inputs are in the right order");
+    assert_eq!(pairs[0][0].compare_explained(&pairs[0][0]).ordering, Ordering::Equal);
+}
@@ -0,0 +1,75 @@
+use advent_of_code::day14::{self, CaveOptions, Day14, Replay, Source, Value};
+use advent_of_code::solution::Solution;
+
+#[test]
+fn replay_shows_the_pile_grain_by_grain()
+{
+    let input = Day14::default().parse(include_str!("../day14/test_input.txt")).unwrap();
+    let replay = Replay::record(&input, false, true);
+    assert_eq!(replay.grains().len(), 24);
+    assert_eq!(replay.grains()[0].path, (0..=8).map(|y| (500, y)).collect::<Vec<_>>());
+
+    let frames: Vec<(usize, String)> = replay.frames(10).collect();
+    assert_eq!(frames.iter().map(|(grains, _)| *grains).collect::<Vec<_>>(), [0, 10, 20, 24]);
+    assert_eq!(frames[3].1, "\
+.......+....
+.......~....
+......~o....
+.....~ooo...
+....~#ooo##.
+...~o#ooo#..
+..~###ooo#..
+..~..oooo#..
+..o.ooooo#..
+.#########..");
+    assert_eq!(frames[0].1.matches('o').count(), 0);
+
+    let floored = Replay::record(&input, true, false);
+    assert_eq!(floored.frames(1).count(), 94);
+}
+
+#[test]
+fn filling_matches_the_simulation()
+{
+    for contents in [include_str!("../day14/test_input.txt"), include_str!("../day14/input.txt")]
+    {
+        let input = Day14::default().parse(contents).unwrap();
+        for floor in [false, true]
+        {
+            assert_eq!(day14::fill(&input, floor), day14::simulate(&input, floor));
+        }
+    }
+}
+
+#[test]
+fn caves_can_have_several_sources_and_water()
+{
+    let example = Day14::default().parse(include_str!("../day14/test_input.txt")).unwrap();
+    let twice = CaveOptions { sources: vec![Source::PUZZLE, "500,0".parse().unwrap()], ..CaveOptions::part_2() };
+    assert_eq!(day14::simulate_cave(&example, &twice), day14::simulate(&example, true));
+
+    // Water fills the bowl and then spills over its rim into the abyss.
+    let bowl = Day14::default().parse("490,5 -> 490,8 -> 510,8 -> 510,5").unwrap();
+    let water = CaveOptions { sources: vec!["500,0:water".parse().unwrap()], ..CaveOptions::part_1() };
+    let pile = day14::simulate_cave(&bowl, &water);
+    assert_eq!(pile.grains, 3 * 19);
+    assert_eq!(pile.map.get((491, 5)), Some(&Value::Water));
+
+    // Between walls sand piles up against them instead of spreading out.
+    let walled = CaveOptions { walls: Some((495, 505)), ..CaveOptions::part_2() };
+    assert_eq!(day14::simulate_cave(&bowl, &walled).grains, 1 + 3 + 5 + 7 + 4 * 9);
+    assert!("500,0:lava".parse::<Source>().is_err());
+}
+
+#[test]
+fn replay_without_sources_is_empty()
+{
+    let options = CaveOptions { sources: Vec::new(), ..CaveOptions::part_2() };
+    for contents in [include_str!("../day14/test_input.txt"), ""]
+    {
+        let input = Day14::default().parse(contents).unwrap();
+        let replay = Replay::record_cave(&input, &options, false);
+        assert!(replay.grains().is_empty());
+        assert_eq!(replay.frames(1).count(), 1);
+    }
+}
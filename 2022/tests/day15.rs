@@ -0,0 +1,65 @@
+use advent_of_code::day15::{export, BeaconSearch, Day15};
+use advent_of_code::solution::{Answer, Solution};
+
+const EXAMPLE: &str = include_str!("../day15/test_input.txt");
+
+#[test]
+fn geometric_search_matches_the_row_scans()
+{
+    for search in [BeaconSearch::Geometric, BeaconSearch::RowScan, BeaconSearch::ParallelRowScan]
+    {
+        let day = Day15::example().with_search(search);
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_2(&input).to_string(), "56000011", "{:?}", search);
+    }
+}
+
+#[test]
+fn parallel_row_scan_covers_every_chunk()
+{
+    // The diamond covers all of a box of several chunks but its bottom right corner, in the last row of the last chunk.
+    let input = Day15::default().parse("Sensor at x=0, y=0: closest beacon is at x=0, y=19999\n").unwrap();
+    for search in [BeaconSearch::RowScan, BeaconSearch::ParallelRowScan]
+    {
+        let day = Day15 { search_box: 0..=10000, ..Day15::default() }.with_search(search);
+        assert_eq!(day.distress_beacon(&input), Some((10000, 10000)), "{:?}", search);
+    }
+}
+
+#[test]
+fn search_box_and_multiplier_are_parameters()
+{
+    let day = Day15 { tuning_multiplier: 10, ..Day15::example() };
+    let input = day.parse(EXAMPLE).unwrap();
+    assert_eq!(day.part_2(&input).to_string(), "151");
+}
+
+#[test]
+fn coverage_map_of_the_example()
+{
+    let day = Day15::example();
+    let input = day.parse(EXAMPLE).unwrap();
+    let gap = day.distress_beacon(&input);
+    assert_eq!(gap, Some((14, 11)));
+
+    let map = export::ascii(&input, &day.search_box, gap, 64);
+    let rows: Vec<&str> = map.lines().collect();
+    assert_eq!(rows.len(), 21);
+    assert_eq!(rows[11], "S#############X######");
+    assert_eq!(rows[16], "#########SB##########");
+    assert_eq!(map.matches('.').count(), 0);
+    assert_eq!(export::ascii(&input, &day.search_box, gap, 7).lines().count(), 7);
+
+    let svg = export::svg(&input, &day.search_box, gap);
+    assert_eq!(svg.matches("<polygon").count(), input.len());
+    assert!(svg.contains(r#"<circle cx="14" cy="11""#));
+}
+
+#[test]
+fn fully_covered_search_box_is_an_error_answer()
+{
+    let day = Day15::default();
+    let input = day.parse("Sensor at x=2000000, y=2000000: closest beacon is at x=2000000, y=6000000\n").unwrap();
+    assert_eq!(day.distress_beacon(&input), None);
+    assert!(matches!(day.part_2(&input), Answer::Error(_)));
+}
@@ -0,0 +1,63 @@
+use advent_of_code::common::grid::{Grid, Rotation, SparseGrid};
+
+const DIGITS: &str = "123\n456\n";
+
+fn digits() -> Grid<u32>
+{
+    Grid::parse(DIGITS, "a digit", |ch| ch.to_digit(10)).unwrap()
+}
+
+#[test]
+fn parses_and_renders_back()
+{
+    let grid = digits();
+    assert_eq!((grid.rows(), grid.columns()), (2, 3));
+    assert_eq!(grid[(1, 2)], 6);
+    assert_eq!(grid.to_string(), DIGITS.trim_end());
+}
+
+#[test]
+fn rejects_ragged_rows_and_unknown_cells()
+{
+    let error = Grid::parse("123\n45\n", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    let error = Grid::parse("123\n4x6\n", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid()
+{
+    let grid = digits();
+    let mut corner: Vec<_> = grid.neighbours_4((0, 0)).collect();
+    corner.sort();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+}
+
+#[test]
+fn rows_columns_and_rotations()
+{
+    let grid = digits();
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+
+    let view = grid.rotated(Rotation::Clockwise);
+    assert_eq!((view.rows(), view.columns()), (3, 2));
+    let first_row: Vec<u32> = (0..view.columns()).map(|column| view[(0, column)]).collect();
+    assert_eq!(first_row, [4, 1]);
+    let view = grid.rotated(Rotation::CounterClockwise);
+    let first_row: Vec<u32> = (0..view.columns()).map(|column| view[(0, column)]).collect();
+    assert_eq!(first_row, [3, 6]);
+}
+
+#[test]
+fn sparse_grid_renders_its_bounding_box()
+{
+    let mut grid = SparseGrid::new();
+    grid.insert((10, 3), '#');
+    grid.insert((12, 5), 'o');
+    assert_eq!(grid.bounding_box(), Some(((10, 3), (12, 5))));
+    assert_eq!(grid.next_below((12, 0)), Some((5, &'o')));
+    assert_eq!(grid.to_string(), "#..\n...\n..o");
+}
@@ -0,0 +1,49 @@
+use advent_of_code::common::interval_set::IntervalSet;
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64>
+{
+    intervals.iter().copied().collect()
+}
+
+fn intervals(set: &IntervalSet<i64>) -> Vec<(i64, i64)>
+{
+    set.intervals().collect()
+}
+
+#[test]
+fn insert_merges_overlapping_and_adjacent_intervals()
+{
+    let set = set(&[(10, 12), (1, 3), (4, 5), (20, 25), (11, 21)]);
+    assert_eq!(intervals(&set), [(1, 5), (10, 25)]);
+    assert_eq!(set.len(), 21);
+    assert!(set.contains(5) && !set.contains(7) && set.contains(25));
+}
+
+#[test]
+fn set_operations()
+{
+    let first = set(&[(0, 10), (20, 30)]);
+    let second = set(&[(5, 25)]);
+    assert_eq!(intervals(&first.union(&second)), [(0, 30)]);
+    assert_eq!(intervals(&first.intersection(&second)), [(5, 10), (20, 25)]);
+    assert_eq!(intervals(&first.difference(&second)), [(0, 4), (26, 30)]);
+    assert!(set(&[(6, 8)]).is_subset(&first));
+    assert!(!second.is_subset(&first));
+    assert!(!set(&[(11, 19)]).intersects(&first));
+}
+
+#[test]
+fn gaps()
+{
+    let set = set(&[(0, 3), (6, 8), (12, 12)]);
+    assert_eq!(set.gaps().collect::<Vec<_>>(), [(4, 5), (9, 11)]);
+    assert_eq!(set.gaps_within(-2, 14).collect::<Vec<_>>(), [(-2, -1), (4, 5), (9, 11), (13, 14)]);
+    for (start, end, first) in [(-2, 14, Some(-2)), (0, 14, Some(4)), (6, 8, None), (7, 12, Some(9)), (12, 12, None), (5, 4, None)]
+    {
+        assert_eq!(set.first_gap_within(start, end), first, "{}..={}", start, end);
+    }
+
+    let mut cleared = set.clone();
+    cleared.clear();
+    assert_eq!(cleared.first_gap_within(0, 3), Some(0));
+}
@@ -0,0 +1,128 @@
+//! Runs both parts of every day on the committed example and puzzle inputs
+//! and compares them with the answers accepted on adventofcode.com.
+
+use std::path::Path;
+
+use advent_of_code::input::InputSource;
+use advent_of_code::{registry, Day, Part};
+
+struct Expected
+{
+    day: u8,
+    example: [&'static str; 2],
+    /// `None` when there is no usable puzzle input in the repository.
+    puzzle: Option<[&'static str; 2]>,
+}
+
+const DAY10_EXAMPLE_PICTURE: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+const EXPECTED: &[Expected] = &[
+    Expected { day: 1, example: ["24000", "45000"], puzzle: Some(["71924", "210406"]) },
+    Expected { day: 2, example: ["15", "12"], puzzle: Some(["14264", "12382"]) },
+    Expected { day: 3, example: ["157", "70"], puzzle: Some(["7795", "2703"]) },
+    Expected { day: 4, example: ["2", "4"], puzzle: Some(["305", "811"]) },
+    Expected { day: 5, example: ["CMZ", "MCD"], puzzle: Some(["TGWSMRBPN", "TZLTLWRNF"]) },
+    Expected { day: 6, example: ["7", "19"], puzzle: Some(["1651", "3837"]) },
+    // There is no personal day 7 input in the repository.
+    Expected { day: 7, example: ["95437", "24933642"], puzzle: None },
+    Expected { day: 8, example: ["21", "8"], puzzle: Some(["1805", "444528"]) },
+    Expected { day: 9, example: ["13", "1"], puzzle: Some(["5779", "2331"]) },
+    Expected { day: 10, example: ["13140", DAY10_EXAMPLE_PICTURE], puzzle: Some(["16480", "PLEFULPB"]) },
+    Expected { day: 11, example: ["10605", "2713310158"], puzzle: Some(["113232", "29703395016"]) },
+    Expected { day: 12, example: ["31", "29"], puzzle: Some(["490", "488"]) },
+    // day13/input.txt was overwritten with the day 14 input, so only the example is checked.
+    Expected { day: 13, example: ["13", "140"], puzzle: None },
+    Expected { day: 14, example: ["24", "93"], puzzle: Some(["838", "27539"]) },
+    Expected { day: 15, example: ["26", "56000011"], puzzle: Some(["5688618", "12625383204261"]) },
+];
+
+fn input(day: u8, example: bool) -> InputSource
+{
+    let file_name = if example { "test_input.txt" } else { "input.txt" };
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("day{:02}", day)).join(file_name);
+    InputSource { path: Some(path), example }
+}
+
+fn check(day: &Day, example: bool, expected: [&str; 2])
+{
+    let contents = input(day.number, example).read(day.number).unwrap();
+    let solution = day.solution(example);
+    let parsed_input = solution.parse_any(&contents).unwrap();
+    for (part, expected_answer) in [Part::One, Part::Two].into_iter().zip(expected)
+    {
+        let answer = solution.solve(parsed_input.as_ref(), part);
+        assert_eq!(answer.to_string(), expected_answer,
+            "day {} part {} on the {} input", day.number, part, if example { "example" } else { "puzzle" });
+    }
+}
+
+fn check_day(number: u8)
+{
+    let days = registry(2022).unwrap();
+    let day = days.iter().find(|day| day.number == number).expect("day is not registered");
+    let expected = EXPECTED.iter().find(|expected| expected.day == number).expect("no expected answers for the day");
+
+    check(day, true, expected.example);
+    if let Some(puzzle) = expected.puzzle
+    {
+        check(day, false, puzzle);
+    }
+}
+
+#[test]
+fn every_registered_day_has_expected_answers()
+{
+    for day in registry(2022).unwrap()
+    {
+        assert!(EXPECTED.iter().any(|expected| expected.day == day.number), "day {} is missing from EXPECTED", day.number);
+    }
+}
+
+#[test]
+fn malformed_input_is_reported_with_its_position()
+{
+    let days = registry(2022).unwrap();
+    let day = days.iter().find(|day| day.number == 9).unwrap();
+    let error = day.solution(false).parse_any("R 4\nU 2\nX 3\n").expect_err("the input is malformed");
+    assert_eq!((error.line, error.column, error.text.as_str()), (3, 1, "X 3"));
+
+    let day = days.iter().find(|day| day.number == 14).unwrap();
+    let error = day.solution(false).parse_any("498,4 -> 498,6\n503,4 -> 502,x\n").expect_err("the input is malformed");
+    assert_eq!((error.line, error.column), (2, 14));
+}
+
+macro_rules! regression_tests {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name()
+            {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+regression_tests! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+    day13 => 13,
+    day14 => 14,
+    day15 => 15,
+}
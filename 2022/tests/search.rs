@@ -0,0 +1,43 @@
+use advent_of_code::common::search::{a_star, bfs, dijkstra};
+
+/// A -1- B -1- C and a direct A -5- C edge.
+fn successors(node: &char) -> Vec<(char, u32)>
+{
+    match node
+    {
+        'A' => vec![('B', 1), ('C', 5)],
+        'B' => vec![('C', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_prefers_the_cheaper_longer_path()
+{
+    let result = dijkstra(['A'], successors);
+    assert_eq!(result.distance(&'C'), Some(2));
+    assert_eq!(result.path_to(&'C'), Some(vec!['A', 'B', 'C']));
+    assert_eq!(dijkstra(['B'], successors).path_to(&'A'), None);
+
+    let unweighted = bfs(['A'], |node| successors(node).into_iter().map(|(next, _)| next));
+    assert_eq!(unweighted.path_to(&'C'), Some(vec!['A', 'C']));
+}
+
+#[test]
+fn a_star_agrees_with_dijkstra_on_a_grid()
+{
+    // Cells of a 20x20 grid with a wall at column 10 open only in the last row.
+    let neighbours = |&(row, column): &(i32, i32)|
+    {
+        [(row - 1, column), (row + 1, column), (row, column - 1), (row, column + 1)].into_iter()
+            .filter(|&(row, column)| (0..20).contains(&row) && (0..20).contains(&column) && (column != 10 || row == 19))
+            .map(|next| (next, 1))
+    };
+    let target = (0, 19);
+    let guided = a_star([(0, 0)], &target, neighbours, |&(row, column)| row.abs_diff(target.0) + column.abs_diff(target.1));
+    let full = dijkstra([(0, 0)], neighbours);
+    assert_eq!(guided.distance(&target), Some(19 + 19 + 19));
+    assert_eq!(guided.distance(&target), full.distance(&target));
+    assert_eq!(guided.path_to(&target).unwrap().len(), 58);
+    assert!(guided.distances.len() < full.distances.len());
+}